@@ -22,6 +22,8 @@ interface GeneratePlayerPayload {
 
 interface SimulateMatchPayload {
   teams: [Team];
+  legacy_logs?: boolean;
+  lang?: string;
 }
 
 export type { GeneratePlayerPayload, SimulateMatchPayload };
@@ -58,7 +58,8 @@ export class SimulatorClient {
         headers: {
           "Content-Type": "application/json",
         },
-        body: JSON.stringify(payload),
+        // The match is still read from the legacy logs
+        body: JSON.stringify({ ...payload, legacy_logs: true }),
       });
 
      if (statusCode !== 200 && statusCode !== 201) {
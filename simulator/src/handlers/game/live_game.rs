@@ -0,0 +1,161 @@
+use actix_web::web;
+use actix_ws::{ Message, MessageStream, Session };
+use std::time::Duration;
+use tokio::time::sleep;
+
+use crate::handlers::game::simulate_game::handle_simulate_game;
+use crate::models::game::live::{ LiveControl, LiveFrame, LiveRequest };
+use crate::models::game::team::Team;
+use crate::services::game::live_frames;
+use crate::validators::game::live::validate_scale;
+
+/// What happened while waiting for the next frame
+enum Wait {
+    Elapsed,
+    FastForward,
+    Closed,
+}
+
+/// Plays a match over a WebSocket: reads the `LiveRequest`, simulates the match and sends
+/// a frame for every minute at the pace of the scale, then the summary, and closes the socket
+pub async fn handle_live_game(mut session: Session, mut stream: MessageStream) {
+    let Some(text) = next_text(&mut session, &mut stream).await else {
+        return;
+    };
+
+    let frames = match simulate_live_game(&text).await {
+        Ok(frames) => frames,
+        Err(message) => {
+            let _ = send(&mut session, &LiveFrame::Error { message }).await;
+            let _ = session.close(None).await;
+            return;
+        }
+    };
+
+    let mut fast_forward = false;
+    for (i, (delay, frame)) in frames.into_iter().enumerate() {
+        if i > 0 && !fast_forward {
+            match wait(delay, &mut session, &mut stream).await {
+                Wait::Elapsed => {}
+                Wait::FastForward => {
+                    fast_forward = true;
+                }
+                Wait::Closed => {
+                    return;
+                }
+            }
+        }
+
+        // The client is gone
+        if send(&mut session, &frame).await.is_err() {
+            return;
+        }
+    }
+
+    let _ = session.close(None).await;
+}
+
+/// Frames of the requested match with the time to wait before sending each one
+async fn simulate_live_game(text: &str) -> Result<Vec<(Duration, LiveFrame)>, String> {
+    let request: LiveRequest = serde_json
+        ::from_str(text)
+        .map_err(|e| format!("Invalid live match request: {}", e))?;
+
+    validate_scale(request.scale)?;
+
+    if request.teams.len() != 2 {
+        return Err("This service requires 2 teams.".to_string());
+    }
+    let teams: [Team; 2] = request.teams
+        .try_into()
+        .map_err(|_| "Error converting to array.".to_string())?;
+
+    let seed = request.seed;
+    let mode = request.mode;
+    let lang = request.lang;
+
+    // The match is CPU bound, keep it away from the actix workers
+    let game = web
+        ::block(move || handle_simulate_game(teams, seed, mode, false, lang.as_deref())).await
+        .map_err(|_| "Live match simulation was interrupted.".to_string())??;
+
+    // Every frame is a match minute, the summary comes right after the last one
+    let minute = Duration::from_secs_f64(60.0 / (request.scale as f64));
+    Ok(
+        live_frames(game)
+            .into_iter()
+            .map(|frame| {
+                let delay = match frame {
+                    LiveFrame::Minute { .. } => minute,
+                    _ => Duration::ZERO,
+                };
+                (delay, frame)
+            })
+            .collect()
+    )
+}
+
+/// Waits `delay` while answering pings and reading the control messages of the client
+async fn wait(delay: Duration, session: &mut Session, stream: &mut MessageStream) -> Wait {
+    let timer = sleep(delay);
+    tokio::pin!(timer);
+
+    loop {
+        tokio::select! {
+            _ = &mut timer => {
+                return Wait::Elapsed;
+            }
+            message = stream.recv() => {
+                match message {
+                    Some(Ok(Message::Text(text))) => {
+                        match serde_json::from_str::<LiveControl>(&text) {
+                            Ok(LiveControl::FastForward) => {
+                                return Wait::FastForward;
+                            }
+                            Err(e) => {
+                                let message = format!("Invalid control message: {}", e);
+                                if send(session, &LiveFrame::Error { message }).await.is_err() {
+                                    return Wait::Closed;
+                                }
+                            }
+                        }
+                    }
+                    Some(Ok(Message::Ping(bytes))) => {
+                        if session.pong(&bytes).await.is_err() {
+                            return Wait::Closed;
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => {
+                        return Wait::Closed;
+                    }
+                    Some(Ok(_)) => {}
+                }
+            }
+        }
+    }
+}
+
+/// First text message of the client, pings are answered while waiting for it
+async fn next_text(session: &mut Session, stream: &mut MessageStream) -> Option<String> {
+    while let Some(Ok(message)) = stream.recv().await {
+        match message {
+            Message::Text(text) => {
+                return Some(text.to_string());
+            }
+            Message::Ping(bytes) => {
+                session.pong(&bytes).await.ok()?;
+            }
+            Message::Close(_) => {
+                return None;
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+async fn send(session: &mut Session, frame: &LiveFrame) -> Result<(), actix_ws::Closed> {
+    let json = serde_json::to_string(frame).unwrap_or_default();
+    session.text(json).await
+}
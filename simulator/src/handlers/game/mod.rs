@@ -1 +1,3 @@
-pub mod simulate_game;
\ No newline at end of file
+pub mod simulate_game;
+pub mod simulate_batch;
+pub mod live_game;
\ No newline at end of file
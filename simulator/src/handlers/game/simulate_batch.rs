@@ -0,0 +1,27 @@
+use crate::models::game::batch_result::BatchResult;
+use crate::models::game::team::Team;
+use crate::services::game::simulate_batch;
+use crate::validators::game::squad::validate_squads;
+use std::panic;
+
+pub const MAX_SIMULATIONS: u32 = 10000;
+
+pub fn handle_simulate_batch(
+    teams: [Team; 2],
+    simulations: u32,
+    seed: Option<u64>
+) -> Result<BatchResult, String> {
+    if simulations == 0 || simulations > MAX_SIMULATIONS {
+        return Err(format!("simulations must be between 1 and {}", MAX_SIMULATIONS));
+    }
+
+    validate_squads(&teams)?;
+
+    // Catch panics safely when simulating the games
+    let safe_result = panic::catch_unwind(|| simulate_batch(teams, simulations, seed));
+
+    match safe_result {
+        Ok(inner_result) => inner_result,
+        Err(_) => Err("Unexpected error while simulating batch (panic captured)".to_string()),
+    }
+}
@@ -1,25 +1,37 @@
+use crate::models::game::commentary::CommentaryTemplates;
 use crate::models::game::game::GameReturn;
+use crate::models::game::match_mode::MatchMode;
 use crate::models::game::team::Team;
-use crate::services::game::simulate_new_game;
+use crate::services::game::{ comment_game, simulate_new_game };
+use crate::validators::game::squad::validate_squads;
 use std::panic;
 
-pub fn handle_simulate_game(teams: [Team; 2]) -> Result<GameReturn, String> {
+pub fn handle_simulate_game(
+    teams: [Team; 2],
+    seed: Option<u64>,
+    mode: MatchMode,
+    legacy_logs: bool,
+    lang: Option<&str>
+) -> Result<GameReturn, String> {
     // Validate starters and bench for both teams
-    for (i, team) in teams.iter().enumerate() {
-        if team.players.len() != 11 {
-            return Err(format!("Team {} must have exactly 11 players", i + 1));
-        }
+    validate_squads(&teams)?;
 
-        if team.bench_players.len() < 5 {
-            return Err(format!("Team {} must have at least 5 players on the bench", i + 1));
-        }
-    }
+    let templates = lang.map(CommentaryTemplates::load).transpose()?;
+    let team_names = [teams[0].name.clone(), teams[1].name.clone()];
 
     // Catch panics safely when simulating the game
-    let safe_result = panic::catch_unwind(|| simulate_new_game(teams));
+    let safe_result = panic::catch_unwind(|| simulate_new_game(teams, seed, mode, legacy_logs));
 
-    match safe_result {
-        Ok(inner_result) => inner_result,
-        Err(_) => Err("Unexpected error while creating game (panic captured)".to_string()),
+    let mut game = match safe_result {
+        Ok(inner_result) => inner_result?,
+        Err(_) => {
+            return Err("Unexpected error while creating game (panic captured)".to_string());
+        }
+    };
+
+    if let Some(templates) = templates {
+        game.commentary = comment_game(&game, templates, team_names);
     }
+
+    Ok(game)
 }
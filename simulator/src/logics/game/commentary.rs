@@ -0,0 +1,46 @@
+use crate::models::game::commentary::GoalContext;
+
+// Goals from this minute on can be a late winner, extra time included
+pub const LATE_GOAL_MINUTE: u8 = 85;
+
+// Goals of a player in the match for a hat-trick
+pub const HAT_TRICK_GOALS: u8 = 3;
+
+/// Context of a goal of `team` at `minute`, `score` is the score after the goal and
+/// `scorer_goals` the goals of the scorer in the match with this one.
+/// A hat-trick goes before a late winner, and a late winner before an equaliser
+pub fn goal_context(score: [u8; 2], team: usize, minute: u8, scorer_goals: u8) -> Option<GoalContext> {
+    let opponent = if team == 0 { 1 } else { 0 };
+
+    if scorer_goals == HAT_TRICK_GOALS {
+        Some(GoalContext::HatTrick)
+    } else if minute >= LATE_GOAL_MINUTE && score[team] == score[opponent] + 1 {
+        Some(GoalContext::LateWinner)
+    } else if score[team] == score[opponent] {
+        Some(GoalContext::Equaliser)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_goal_context() {
+        assert_eq!(goal_context([1, 0], 0, 20, 1), None);
+        assert_eq!(goal_context([1, 1], 1, 30, 1), Some(GoalContext::Equaliser));
+        assert_eq!(goal_context([2, 1], 0, 88, 1), Some(GoalContext::LateWinner));
+        // Going two up late is not a winner
+        assert_eq!(goal_context([3, 1], 0, 88, 1), None);
+        assert_eq!(goal_context([1, 2], 1, 105, 2), Some(GoalContext::LateWinner));
+    }
+
+    #[test]
+    fn test_hat_trick_goes_first() {
+        assert_eq!(goal_context([3, 3], 0, 90, HAT_TRICK_GOALS), Some(GoalContext::HatTrick));
+        // The fourth goal is not a hat-trick any more
+        assert_eq!(goal_context([4, 0], 0, 70, HAT_TRICK_GOALS + 1), None);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod stoppage_time;
+pub mod shootout;
+pub mod tactics;
+pub mod commentary;
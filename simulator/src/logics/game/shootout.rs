@@ -0,0 +1,54 @@
+// Kicks each team takes before the shootout goes to sudden death
+pub const SHOOTOUT_KICKS: u8 = 5;
+
+// Scoring chance of a kick between a taker and a goalkeeper of the same level
+pub const BASE_SHOOTOUT_SCORE_CHANCE: f32 = 0.75;
+
+// Share (%) of the missed kicks saved by the goalkeeper, the rest miss the target
+pub const SHOOTOUT_SAVE_SHARE: u8 = 70;
+
+/// Chance (0-1) of scoring a shootout kick from the taker `shot` value
+/// (finishing and composure) against the goalkeeper `save` value (intuition and reflexes)
+pub fn shootout_score_chance(shot: f32, save: f32) -> f32 {
+    (BASE_SHOOTOUT_SCORE_CHANCE + (shot - save) * 0.01).clamp(0.4, 0.95)
+}
+
+/// A shootout is decided when a team can't catch up with its remaining kicks,
+/// in sudden death after both teams have taken the same number of kicks
+pub fn is_shootout_decided(score: [u8; 2], kicks: [u8; 2]) -> bool {
+    if kicks[0] < SHOOTOUT_KICKS || kicks[1] < SHOOTOUT_KICKS {
+        let remaining = |team: usize| SHOOTOUT_KICKS - kicks[team].min(SHOOTOUT_KICKS);
+        return score[0] + remaining(0) < score[1] || score[1] + remaining(1) < score[0];
+    }
+
+    kicks[0] == kicks[1] && score[0] != score[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shootout_score_chance() {
+        assert!(shootout_score_chance(90.0, 50.0) > shootout_score_chance(50.0, 90.0));
+        assert_eq!(shootout_score_chance(60.0, 60.0), BASE_SHOOTOUT_SCORE_CHANCE);
+    }
+
+    #[test]
+    fn test_shootout_decided_early() {
+        assert!(!is_shootout_decided([2, 0], [2, 2]));
+        // 3-0 after three kicks each, the second team can reach 2 at most
+        assert!(is_shootout_decided([3, 0], [3, 3]));
+        // 3-1 with the second team still having two kicks
+        assert!(!is_shootout_decided([3, 1], [4, 3]));
+        assert!(is_shootout_decided([4, 2], [4, 4]));
+    }
+
+    #[test]
+    fn test_sudden_death() {
+        assert!(!is_shootout_decided([5, 5], [5, 5]));
+        assert!(!is_shootout_decided([6, 5], [6, 5]));
+        assert!(is_shootout_decided([6, 5], [6, 6]));
+        assert!(!is_shootout_decided([6, 6], [6, 6]));
+    }
+}
@@ -0,0 +1,84 @@
+use crate::models::game::booking::BookingType;
+use crate::models::game::match_event::{ MatchEvent, PenaltyOutcome };
+use crate::models::game::team_stats::InjuryType;
+
+// Minutes added for every kind of stoppage during a half
+pub const GOAL_ADDED_TIME: f32 = 0.5;
+pub const SUBSTITUTION_ADDED_TIME: f32 = 0.4;
+pub const SOFT_INJURY_ADDED_TIME: f32 = 0.5;
+pub const HARD_INJURY_ADDED_TIME: f32 = 1.5;
+pub const YELLOW_CARD_ADDED_TIME: f32 = 0.25;
+pub const RED_CARD_ADDED_TIME: f32 = 0.5;
+pub const PENALTY_ADDED_TIME: f32 = 0.5;
+
+// Chance (%) of a goal being checked by the VAR
+pub const VAR_CHECK_CHANCE: u8 = 25;
+
+// Minutes lost by a VAR check, every awarded penalty is checked
+pub const VAR_CHECK_ADDED_TIME: f32 = 1.0;
+
+// Stoppage time announced at the end of every half
+pub const MIN_STOPPAGE_TIME: u8 = 1;
+pub const MAX_STOPPAGE_TIME: u8 = 10;
+
+/// Minutes lost by a match `event`
+pub fn added_time(event: &MatchEvent) -> f32 {
+    match event {
+        MatchEvent::Goal { .. } => GOAL_ADDED_TIME,
+        MatchEvent::Substitution { .. } => SUBSTITUTION_ADDED_TIME,
+        MatchEvent::Injury { injury: InjuryType::Soft, .. } => SOFT_INJURY_ADDED_TIME,
+        MatchEvent::Injury { injury: InjuryType::Hard, .. } => HARD_INJURY_ADDED_TIME,
+        MatchEvent::Card { card: BookingType::Yellow, .. } => YELLOW_CARD_ADDED_TIME,
+        MatchEvent::Card { .. } => RED_CARD_ADDED_TIME,
+        MatchEvent::Penalty { outcome: PenaltyOutcome::Awarded, .. } =>
+            PENALTY_ADDED_TIME + VAR_CHECK_ADDED_TIME,
+        _ => 0.0,
+    }
+}
+
+/// Goals can be checked by the VAR, the check is rolled by the game
+pub fn can_be_checked(event: &MatchEvent) -> bool {
+    matches!(event, MatchEvent::Goal { .. })
+}
+
+/// Stoppage time announced for a half that lost `lost_minutes`
+pub fn stoppage_time(lost_minutes: f32) -> u8 {
+    (lost_minutes.round() as u8).clamp(MIN_STOPPAGE_TIME, MAX_STOPPAGE_TIME)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::game::match_event::{ EventPlayer, GoalKind };
+
+    fn player() -> EventPlayer {
+        EventPlayer { team: 0, index: 9, name: "Player".to_string(), number: 9 }
+    }
+
+    #[test]
+    fn test_added_time() {
+        let injury = |injury| MatchEvent::Injury { player: player(), injury, days_out: 7 };
+        assert!(added_time(&injury(InjuryType::Hard)) > added_time(&injury(InjuryType::Soft)));
+
+        let goal = MatchEvent::Goal {
+            scorer: player(),
+            assistant: None,
+            kind: GoalKind::Shot,
+            zone: None,
+            xg: None,
+        };
+        assert!(added_time(&goal) > 0.0);
+        assert!(can_be_checked(&goal));
+
+        let control = MatchEvent::Control { player: player(), success: true };
+        assert_eq!(added_time(&control), 0.0);
+        assert!(!can_be_checked(&control));
+    }
+
+    #[test]
+    fn test_stoppage_time_limits() {
+        assert_eq!(stoppage_time(0.0), MIN_STOPPAGE_TIME);
+        assert_eq!(stoppage_time(3.4), 3);
+        assert_eq!(stoppage_time(40.0), MAX_STOPPAGE_TIME);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::models::game::tactics::{ Pressing, Tempo, Width };
+
+// Action weight moved per level of mentality, attacking teams pass less and shoot
+// and dribble more
+pub const MENTALITY_SHOOT_SHIFT: i32 = 4;
+pub const MENTALITY_DRIBBLE_SHIFT: i32 = 3;
+pub const MENTALITY_PASS_SHIFT: i32 = -7;
+
+// Fast teams play more long balls, slow teams keep the ball on the ground
+pub const TEMPO_LONG_PASS_SHIFT: i32 = 5;
+
+// Wide teams cross more, narrow teams combine through the middle
+pub const WIDTH_CROSS_SHIFT: i32 = 5;
+
+// Actions played every minute with both teams at normal tempo
+pub const BASE_ACTIONS_PER_MINUTE: u8 = 5;
+
+// Extra foul chance (%) of a team pressing high, a low block fouls less
+pub const HIGH_PRESSING_FOUL_BONUS: f32 = 8.0;
+pub const LOW_PRESSING_FOUL_BONUS: f32 = -5.0;
+
+// Extra defender score in a dribble duel of a team pressing high
+pub const PRESSING_TACKLE_BONUS: i32 = 3;
+
+/// Moves `delta` of weight into an action, actions the position never takes stay at 0
+pub fn shift_weight(weight: u32, delta: i32) -> u32 {
+    if weight == 0 {
+        return 0;
+    }
+    ((weight as i32) + delta).max(1) as u32
+}
+
+/// Signed shift of tempo and width, -1 for slow and narrow and 1 for fast and wide
+pub fn tempo_level(tempo: &Tempo) -> i32 {
+    match tempo {
+        Tempo::Slow => -1,
+        Tempo::Normal => 0,
+        Tempo::Fast => 1,
+    }
+}
+
+pub fn width_level(width: &Width) -> i32 {
+    match width {
+        Width::Narrow => -1,
+        Width::Normal => 0,
+        Width::Wide => 1,
+    }
+}
+
+/// Actions played in a minute, each team speeds up or slows down the game
+pub fn actions_per_minute(tempos: [&Tempo; 2]) -> u8 {
+    let shift: i32 = tempos.iter().map(|t| tempo_level(t)).sum();
+    ((BASE_ACTIONS_PER_MINUTE as i32) + shift) as u8
+}
+
+/// Share of the pressing drain paid by the team without the ball
+pub fn pressing_drain_factor(pressing: &Pressing) -> f32 {
+    match pressing {
+        Pressing::Low => 0.5,
+        Pressing::Medium => 1.0,
+        Pressing::High => 2.0,
+    }
+}
+
+pub fn pressing_foul_bonus(pressing: &Pressing) -> f32 {
+    match pressing {
+        Pressing::Low => LOW_PRESSING_FOUL_BONUS,
+        Pressing::Medium => 0.0,
+        Pressing::High => HIGH_PRESSING_FOUL_BONUS,
+    }
+}
+
+pub fn pressing_tackle_bonus(pressing: &Pressing) -> i32 {
+    match pressing {
+        Pressing::Low => -PRESSING_TACKLE_BONUS,
+        Pressing::Medium => 0,
+        Pressing::High => PRESSING_TACKLE_BONUS,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shift_weight() {
+        assert_eq!(shift_weight(0, 10), 0);
+        assert_eq!(shift_weight(10, 5), 15);
+        assert_eq!(shift_weight(3, -10), 1);
+    }
+
+    #[test]
+    fn test_actions_per_minute() {
+        assert_eq!(actions_per_minute([&Tempo::Normal, &Tempo::Normal]), BASE_ACTIONS_PER_MINUTE);
+        assert_eq!(actions_per_minute([&Tempo::Fast, &Tempo::Fast]), BASE_ACTIONS_PER_MINUTE + 2);
+        assert_eq!(actions_per_minute([&Tempo::Slow, &Tempo::Fast]), BASE_ACTIONS_PER_MINUTE);
+    }
+
+    #[test]
+    fn test_pressing() {
+        assert!(pressing_drain_factor(&Pressing::High) > pressing_drain_factor(&Pressing::Low));
+        assert!(pressing_foul_bonus(&Pressing::High) > pressing_foul_bonus(&Pressing::Low));
+        assert!(pressing_tackle_bonus(&Pressing::High) > pressing_tackle_bonus(&Pressing::Low));
+    }
+}
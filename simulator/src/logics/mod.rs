@@ -1 +1,2 @@
-pub mod player;
\ No newline at end of file
+pub mod player;
+pub mod game;
@@ -1,3 +1,6 @@
+use crate::models::player::position::Position;
+use crate::models::game::shot_zone::ShotZone;
+
 pub const ACTIONS: &[(&str, u8)] = &[
     ("shoot", 25),
     ("pass", 40),
@@ -5,4 +8,43 @@ pub const ACTIONS: &[(&str, u8)] = &[
     ("advance", 0),
     ("long_pass", 3),
     ("cross", 2),
-];
\ No newline at end of file
+];
+
+// Receiver preference of a short pass by the receiver's position
+pub const PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 0),
+    (Position::Defender, 2),
+    (Position::Left_Back, 3),
+    (Position::Right_Back, 3),
+    (Position::Defensive_Midfield, 6),
+    (Position::Midfielder, 12),
+    (Position::Left_Midfield, 10),
+    (Position::Right_Midfield, 10),
+    (Position::Attacking_Midfield, 4),
+    (Position::Left_Wing, 14),
+    (Position::Right_Wing, 14),
+    (Position::Striker, 22),
+];
+
+// Receiver preference of a long pass, distant lines are favoured
+pub const LONG_PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 1),
+    (Position::Defender, 3),
+    (Position::Left_Back, 6),
+    (Position::Right_Back, 6),
+    (Position::Defensive_Midfield, 4),
+    (Position::Midfielder, 3),
+    (Position::Left_Midfield, 12),
+    (Position::Right_Midfield, 12),
+    (Position::Attacking_Midfield, 1),
+    (Position::Left_Wing, 16),
+    (Position::Right_Wing, 16),
+    (Position::Striker, 18),
+];
+
+// Where the shots of this position are taken from
+pub const SHOT_ZONES: &[(ShotZone, u8)] = &[
+    (ShotZone::LongRange, 30),
+    (ShotZone::EdgeOfBox, 45),
+    (ShotZone::InsideBox, 25),
+];
@@ -1,3 +1,6 @@
+use crate::models::player::position::Position;
+use crate::models::game::shot_zone::ShotZone;
+
 pub const ACTIONS: &[(&str, u8)] = &[
 ("shoot", 0),
     ("pass", 60),
@@ -5,4 +8,43 @@ pub const ACTIONS: &[(&str, u8)] = &[
     ("advance", 0),
     ("long_pass", 20),
     ("cross", 0)
-];
\ No newline at end of file
+];
+
+// Receiver preference of a short pass by the receiver's position
+pub const PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 8),
+    (Position::Defender, 15),
+    (Position::Left_Back, 12),
+    (Position::Right_Back, 12),
+    (Position::Defensive_Midfield, 20),
+    (Position::Midfielder, 15),
+    (Position::Left_Midfield, 6),
+    (Position::Right_Midfield, 6),
+    (Position::Attacking_Midfield, 4),
+    (Position::Left_Wing, 2),
+    (Position::Right_Wing, 2),
+    (Position::Striker, 2),
+];
+
+// Receiver preference of a long pass, distant lines are favoured
+pub const LONG_PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 1),
+    (Position::Defender, 1),
+    (Position::Left_Back, 3),
+    (Position::Right_Back, 3),
+    (Position::Defensive_Midfield, 3),
+    (Position::Midfielder, 6),
+    (Position::Left_Midfield, 12),
+    (Position::Right_Midfield, 12),
+    (Position::Attacking_Midfield, 10),
+    (Position::Left_Wing, 16),
+    (Position::Right_Wing, 16),
+    (Position::Striker, 22),
+];
+
+// Where the shots of this position are taken from
+pub const SHOT_ZONES: &[(ShotZone, u8)] = &[
+    (ShotZone::LongRange, 55),
+    (ShotZone::EdgeOfBox, 30),
+    (ShotZone::InsideBox, 15),
+];
@@ -1,3 +1,6 @@
+use crate::models::player::position::Position;
+use crate::models::game::shot_zone::ShotZone;
+
 pub const ACTIONS: &[(&str, u8)] = &[
     ("shoot", 5),
     ("pass", 40),
@@ -5,4 +8,43 @@ pub const ACTIONS: &[(&str, u8)] = &[
     ("advance", 0),
     ("long_pass", 35),
     ("cross", 0)
-];
\ No newline at end of file
+];
+
+// Receiver preference of a short pass by the receiver's position
+pub const PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 2),
+    (Position::Defender, 14),
+    (Position::Left_Back, 10),
+    (Position::Right_Back, 10),
+    (Position::Defensive_Midfield, 5),
+    (Position::Midfielder, 18),
+    (Position::Left_Midfield, 10),
+    (Position::Right_Midfield, 10),
+    (Position::Attacking_Midfield, 12),
+    (Position::Left_Wing, 4),
+    (Position::Right_Wing, 4),
+    (Position::Striker, 3),
+];
+
+// Receiver preference of a long pass, distant lines are favoured
+pub const LONG_PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 1),
+    (Position::Defender, 1),
+    (Position::Left_Back, 6),
+    (Position::Right_Back, 6),
+    (Position::Defensive_Midfield, 1),
+    (Position::Midfielder, 3),
+    (Position::Left_Midfield, 10),
+    (Position::Right_Midfield, 10),
+    (Position::Attacking_Midfield, 8),
+    (Position::Left_Wing, 18),
+    (Position::Right_Wing, 18),
+    (Position::Striker, 22),
+];
+
+// Where the shots of this position are taken from
+pub const SHOT_ZONES: &[(ShotZone, u8)] = &[
+    (ShotZone::LongRange, 55),
+    (ShotZone::EdgeOfBox, 35),
+    (ShotZone::InsideBox, 10),
+];
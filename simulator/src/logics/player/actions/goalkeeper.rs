@@ -1,3 +1,6 @@
+use crate::models::player::position::Position;
+use crate::models::game::shot_zone::ShotZone;
+
 pub const ACTIONS: &[(&str, u8)] = &[
     ("shoot", 0),
     ("pass", 60),
@@ -6,3 +9,42 @@ pub const ACTIONS: &[(&str, u8)] = &[
     ("long_pass", 39),
     ("cross", 0),
 ];
+
+// Receiver preference of a short pass by the receiver's position
+pub const PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 0),
+    (Position::Defender, 30),
+    (Position::Left_Back, 20),
+    (Position::Right_Back, 20),
+    (Position::Defensive_Midfield, 15),
+    (Position::Midfielder, 8),
+    (Position::Left_Midfield, 3),
+    (Position::Right_Midfield, 3),
+    (Position::Attacking_Midfield, 1),
+    (Position::Left_Wing, 1),
+    (Position::Right_Wing, 1),
+    (Position::Striker, 1),
+];
+
+// Receiver preference of a long pass, distant lines are favoured
+pub const LONG_PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 0),
+    (Position::Defender, 2),
+    (Position::Left_Back, 4),
+    (Position::Right_Back, 4),
+    (Position::Defensive_Midfield, 6),
+    (Position::Midfielder, 10),
+    (Position::Left_Midfield, 12),
+    (Position::Right_Midfield, 12),
+    (Position::Attacking_Midfield, 12),
+    (Position::Left_Wing, 16),
+    (Position::Right_Wing, 16),
+    (Position::Striker, 20),
+];
+
+// Where the shots of this position are taken from
+pub const SHOT_ZONES: &[(ShotZone, u8)] = &[
+    (ShotZone::LongRange, 90),
+    (ShotZone::EdgeOfBox, 10),
+    (ShotZone::InsideBox, 0),
+];
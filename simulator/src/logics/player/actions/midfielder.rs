@@ -1,3 +1,6 @@
+use crate::models::player::position::Position;
+use crate::models::game::shot_zone::ShotZone;
+
 pub const ACTIONS: &[(&str, u8)] = &[
     ("shoot", 10),
     ("pass", 45),
@@ -6,3 +9,42 @@ pub const ACTIONS: &[(&str, u8)] = &[
     ("long_pass", 10),
     ("cross", 15),
 ];
+
+// Receiver preference of a short pass by the receiver's position
+pub const PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 1),
+    (Position::Defender, 6),
+    (Position::Left_Back, 6),
+    (Position::Right_Back, 6),
+    (Position::Defensive_Midfield, 12),
+    (Position::Midfielder, 10),
+    (Position::Left_Midfield, 12),
+    (Position::Right_Midfield, 12),
+    (Position::Attacking_Midfield, 16),
+    (Position::Left_Wing, 8),
+    (Position::Right_Wing, 8),
+    (Position::Striker, 8),
+];
+
+// Receiver preference of a long pass, distant lines are favoured
+pub const LONG_PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 1),
+    (Position::Defender, 1),
+    (Position::Left_Back, 4),
+    (Position::Right_Back, 4),
+    (Position::Defensive_Midfield, 2),
+    (Position::Midfielder, 2),
+    (Position::Left_Midfield, 10),
+    (Position::Right_Midfield, 10),
+    (Position::Attacking_Midfield, 6),
+    (Position::Left_Wing, 18),
+    (Position::Right_Wing, 18),
+    (Position::Striker, 24),
+];
+
+// Where the shots of this position are taken from
+pub const SHOT_ZONES: &[(ShotZone, u8)] = &[
+    (ShotZone::LongRange, 45),
+    (ShotZone::EdgeOfBox, 40),
+    (ShotZone::InsideBox, 15),
+];
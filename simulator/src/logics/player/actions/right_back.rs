@@ -1,3 +1,6 @@
+use crate::models::player::position::Position;
+use crate::models::game::shot_zone::ShotZone;
+
 pub const ACTIONS: &[(&str, u8)] = &[
     ("shoot", 0),
     ("pass", 50),
@@ -6,3 +9,42 @@ pub const ACTIONS: &[(&str, u8)] = &[
     ("long_pass", 25),
     ("cross", 0),
 ];
+
+// Receiver preference of a short pass by the receiver's position
+pub const PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 4),
+    (Position::Defender, 20),
+    (Position::Left_Back, 12),
+    (Position::Right_Back, 0),
+    (Position::Defensive_Midfield, 14),
+    (Position::Midfielder, 10),
+    (Position::Left_Midfield, 2),
+    (Position::Right_Midfield, 10),
+    (Position::Attacking_Midfield, 4),
+    (Position::Left_Wing, 1),
+    (Position::Right_Wing, 12),
+    (Position::Striker, 2),
+];
+
+// Receiver preference of a long pass, distant lines are favoured
+pub const LONG_PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 1),
+    (Position::Defender, 2),
+    (Position::Left_Back, 10),
+    (Position::Right_Back, 1),
+    (Position::Defensive_Midfield, 3),
+    (Position::Midfielder, 6),
+    (Position::Left_Midfield, 14),
+    (Position::Right_Midfield, 6),
+    (Position::Attacking_Midfield, 8),
+    (Position::Left_Wing, 14),
+    (Position::Right_Wing, 12),
+    (Position::Striker, 20),
+];
+
+// Where the shots of this position are taken from
+pub const SHOT_ZONES: &[(ShotZone, u8)] = &[
+    (ShotZone::LongRange, 55),
+    (ShotZone::EdgeOfBox, 30),
+    (ShotZone::InsideBox, 15),
+];
@@ -1,3 +1,6 @@
+use crate::models::player::position::Position;
+use crate::models::game::shot_zone::ShotZone;
+
 pub const ACTIONS: &[(&str, u8)] = &[
     ("shoot", 15),
     ("pass", 35),
@@ -6,3 +9,42 @@ pub const ACTIONS: &[(&str, u8)] = &[
     ("long_pass", 15),
     ("cross", 15),
 ];
+
+// Receiver preference of a short pass by the receiver's position
+pub const PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 1),
+    (Position::Defender, 4),
+    (Position::Left_Back, 1),
+    (Position::Right_Back, 14),
+    (Position::Defensive_Midfield, 10),
+    (Position::Midfielder, 16),
+    (Position::Left_Midfield, 3),
+    (Position::Right_Midfield, 3),
+    (Position::Attacking_Midfield, 12),
+    (Position::Left_Wing, 2),
+    (Position::Right_Wing, 18),
+    (Position::Striker, 10),
+];
+
+// Receiver preference of a long pass, distant lines are favoured
+pub const LONG_PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 1),
+    (Position::Defender, 2),
+    (Position::Left_Back, 8),
+    (Position::Right_Back, 2),
+    (Position::Defensive_Midfield, 3),
+    (Position::Midfielder, 4),
+    (Position::Left_Midfield, 18),
+    (Position::Right_Midfield, 1),
+    (Position::Attacking_Midfield, 6),
+    (Position::Left_Wing, 18),
+    (Position::Right_Wing, 8),
+    (Position::Striker, 22),
+];
+
+// Where the shots of this position are taken from
+pub const SHOT_ZONES: &[(ShotZone, u8)] = &[
+    (ShotZone::LongRange, 40),
+    (ShotZone::EdgeOfBox, 35),
+    (ShotZone::InsideBox, 25),
+];
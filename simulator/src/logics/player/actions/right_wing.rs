@@ -1,3 +1,6 @@
+use crate::models::player::position::Position;
+use crate::models::game::shot_zone::ShotZone;
+
 pub const ACTIONS: &[(&str, u8)] = &[
     ("shoot", 20),
     ("pass", 15),
@@ -6,3 +9,42 @@ pub const ACTIONS: &[(&str, u8)] = &[
     ("long_pass", 5),
     ("cross", 25),
 ];
+
+// Receiver preference of a short pass by the receiver's position
+pub const PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 0),
+    (Position::Defender, 1),
+    (Position::Left_Back, 1),
+    (Position::Right_Back, 10),
+    (Position::Defensive_Midfield, 3),
+    (Position::Midfielder, 8),
+    (Position::Left_Midfield, 2),
+    (Position::Right_Midfield, 16),
+    (Position::Attacking_Midfield, 16),
+    (Position::Left_Wing, 6),
+    (Position::Right_Wing, 2),
+    (Position::Striker, 24),
+];
+
+// Receiver preference of a long pass, distant lines are favoured
+pub const LONG_PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 1),
+    (Position::Defender, 4),
+    (Position::Left_Back, 10),
+    (Position::Right_Back, 4),
+    (Position::Defensive_Midfield, 6),
+    (Position::Midfielder, 6),
+    (Position::Left_Midfield, 14),
+    (Position::Right_Midfield, 4),
+    (Position::Attacking_Midfield, 8),
+    (Position::Left_Wing, 20),
+    (Position::Right_Wing, 1),
+    (Position::Striker, 18),
+];
+
+// Where the shots of this position are taken from
+pub const SHOT_ZONES: &[(ShotZone, u8)] = &[
+    (ShotZone::LongRange, 20),
+    (ShotZone::EdgeOfBox, 35),
+    (ShotZone::InsideBox, 45),
+];
@@ -1,3 +1,6 @@
+use crate::models::player::position::Position;
+use crate::models::game::shot_zone::ShotZone;
+
 pub const ACTIONS: &[(&str, u8)] = &[
     ("shoot", 55),
     ("pass", 15),
@@ -6,3 +9,42 @@ pub const ACTIONS: &[(&str, u8)] = &[
     ("long_pass", 5),
     ("cross", 5),
 ];
+
+// Receiver preference of a short pass by the receiver's position
+pub const PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 0),
+    (Position::Defender, 1),
+    (Position::Left_Back, 2),
+    (Position::Right_Back, 2),
+    (Position::Defensive_Midfield, 4),
+    (Position::Midfielder, 12),
+    (Position::Left_Midfield, 8),
+    (Position::Right_Midfield, 8),
+    (Position::Attacking_Midfield, 20),
+    (Position::Left_Wing, 16),
+    (Position::Right_Wing, 16),
+    (Position::Striker, 12),
+];
+
+// Receiver preference of a long pass, distant lines are favoured
+pub const LONG_PASSES: &[(Position, u8)] = &[
+    (Position::Goalkeeper, 2),
+    (Position::Defender, 6),
+    (Position::Left_Back, 8),
+    (Position::Right_Back, 8),
+    (Position::Defensive_Midfield, 12),
+    (Position::Midfielder, 10),
+    (Position::Left_Midfield, 12),
+    (Position::Right_Midfield, 12),
+    (Position::Attacking_Midfield, 8),
+    (Position::Left_Wing, 14),
+    (Position::Right_Wing, 14),
+    (Position::Striker, 6),
+];
+
+// Where the shots of this position are taken from
+pub const SHOT_ZONES: &[(ShotZone, u8)] = &[
+    (ShotZone::LongRange, 5),
+    (ShotZone::EdgeOfBox, 30),
+    (ShotZone::InsideBox, 65),
+];
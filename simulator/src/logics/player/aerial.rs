@@ -0,0 +1,98 @@
+use crate::models::player::position::Position;
+
+// Heights under this don't count in an aerial duel
+pub const MIN_AERIAL_HEIGHT: f32 = 160.0;
+
+// Weight of every centimetre over the minimum height and of physical and aggression
+pub const HEIGHT_FACTOR: f32 = 1.5;
+pub const PHYSICAL_FACTOR: f32 = 0.5;
+pub const AGGRESSION_FACTOR: f32 = 0.25;
+
+// Share (%) of won duels the attacker heads at goal, the rest are headed down to a teammate
+pub const HEADER_SHOT_CHANCE: u8 = 50;
+
+// Share (%) of cleared crosses that go out for a corner
+pub const CLEARED_CORNER_CHANCE: u8 = 10;
+
+// Share (%) of failed crosses cut out by a defender, going wide for a goal kick
+// and deflected for a corner
+pub const INTERCEPTED_CROSS_CHANCE: u8 = 55;
+pub const WIDE_CROSS_CHANCE: u8 = 30;
+
+/// Players attacking a cross in the box
+pub fn is_box_position(position: &Position) -> bool {
+    matches!(
+        position,
+        Position::Striker |
+            Position::Attacking_Midfield |
+            Position::Left_Wing |
+            Position::Right_Wing |
+            Position::Midfielder
+    )
+}
+
+/// Lines between a player and the opponent box, 0 for the players attacking it
+pub fn box_distance(position: &Position) -> u8 {
+    match position {
+        position if is_box_position(position) => 0,
+        Position::Left_Midfield | Position::Right_Midfield | Position::Defensive_Midfield => 1,
+        Position::Left_Back | Position::Right_Back => 2,
+        Position::Defender => 3,
+        _ => 4,
+    }
+}
+
+/// Strength of a player in the air, `performance` is the energy factor of the player
+pub fn aerial_score(height_cm: u8, physical: u8, aggression: u8, performance: f32) -> f32 {
+    let height = ((height_cm as f32) - MIN_AERIAL_HEIGHT).max(0.0);
+
+    (height * HEIGHT_FACTOR +
+        (physical as f32) * PHYSICAL_FACTOR +
+        (aggression as f32) * AGGRESSION_FACTOR) *
+        performance
+}
+
+/// Chance (%) of the attacker winning the aerial duel against the marker
+pub fn aerial_win_chance(attacker: f32, defender: f32) -> f32 {
+    (35.0 + (attacker - defender) * 0.6).clamp(15.0, 85.0)
+}
+
+/// Chance (%) of a header at goal beating the goalkeeper, headers are harder to
+/// place than shots and the duel strength makes up for the lack of a header skill
+pub fn header_scoring_chance(shooting: u8, aerial: f32, goalkeeper: f32) -> f32 {
+    let attack = (shooting as f32) * 0.6 + aerial * 0.4;
+
+    (4.0 + (attack - goalkeeper) * 0.25).clamp(3.0, 25.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_height_wins_aerial_duels() {
+        let tall = aerial_score(195, 70, 60, 1.0);
+        let short = aerial_score(170, 70, 60, 1.0);
+
+        assert!(tall > short);
+        assert!(aerial_win_chance(tall, short) > 50.0);
+        assert!(aerial_win_chance(short, tall) < 50.0);
+        assert!(aerial_score(195, 70, 60, 0.7) < tall, "Tired players jump less");
+    }
+
+    #[test]
+    fn test_box_distance() {
+        assert_eq!(box_distance(&Position::Striker), 0);
+        assert_eq!(box_distance(&Position::Midfielder), 0);
+        assert!(box_distance(&Position::Left_Midfield) < box_distance(&Position::Left_Back));
+        assert!(box_distance(&Position::Left_Back) < box_distance(&Position::Defender));
+        assert!(box_distance(&Position::Defender) < box_distance(&Position::Goalkeeper));
+    }
+
+    #[test]
+    fn test_header_scoring_chance() {
+        assert!(header_scoring_chance(90, 60.0, 60.0) > header_scoring_chance(40, 60.0, 60.0));
+        assert!(header_scoring_chance(99, 99.0, 0.0) <= 25.0);
+        assert!(header_scoring_chance(0, 0.0, 99.0) >= 3.0);
+    }
+}
@@ -0,0 +1,50 @@
+// Energy lost by every player on the pitch each minute
+pub const MINUTE_DRAIN: f32 = 0.4;
+
+// Extra energy lost each minute by the team without the ball, chasing and pressing
+pub const PRESSING_DRAIN: f32 = 0.15;
+
+// Energy lost by the player who takes an action with the ball
+pub const ACTION_DRAIN: f32 = 0.3;
+
+// Energy lost by a player involved in a physical duel
+pub const DUEL_DRAIN: f32 = 0.5;
+
+// Goalkeepers run much less than outfield players
+pub const GOALKEEPER_MINUTE_FACTOR: f32 = 0.3;
+
+// Teams with the EndlessEngine aura get tired slower
+pub const ENDLESS_ENGINE_FACTOR: f32 = 0.8;
+
+pub const FULL_ENERGY: f32 = 100.0;
+
+/// How much of every drain a player suffers depending on stamina,
+/// 99 stamina halves the drain and 0 stamina increases it by 50%
+pub fn stamina_drain_factor(stamina: u8) -> f32 {
+    (1.5 - (stamina as f32) / 100.0).clamp(0.5, 1.5)
+}
+
+/// Share of the player's skills available with the current energy,
+/// an exhausted player keeps 70% of their level
+pub fn energy_performance_factor(energy: f32) -> f32 {
+    0.7 + 0.3 * (energy / FULL_ENERGY).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stamina_slows_the_drain() {
+        assert!(stamina_drain_factor(99) < stamina_drain_factor(55));
+        assert!(stamina_drain_factor(55) < stamina_drain_factor(0));
+        assert_eq!(stamina_drain_factor(0), 1.5);
+    }
+
+    #[test]
+    fn test_energy_performance_bounds() {
+        assert_eq!(energy_performance_factor(FULL_ENERGY), 1.0);
+        assert_eq!(energy_performance_factor(0.0), 0.7);
+        assert!(energy_performance_factor(50.0) < energy_performance_factor(80.0));
+    }
+}
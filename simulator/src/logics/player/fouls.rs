@@ -0,0 +1,154 @@
+use crate::models::player::instructions::DefensiveInstruction;
+
+// Base chance (%) of a beaten defender stopping the attacker with a foul
+pub const BASE_FOUL_CHANCE: f32 = 14.0;
+
+// Extra foul chance (%) per point of aggression
+pub const AGGRESSION_FOUL_FACTOR: f32 = 0.1;
+
+// Extra foul chance (%) for players with the Destroy instruction
+pub const DESTROY_FOUL_BONUS: f32 = 12.0;
+
+// Extra foul chance (%) when the defending team is losing in the last 20 minutes
+pub const LOSING_LATE_FOUL_BONUS: f32 = 4.0;
+
+// Passive players hold back from challenges
+pub const PASSIVE_FOUL_FACTOR: f32 = 0.5;
+
+// Booked players are more careful
+pub const BOOKED_FOUL_FACTOR: f32 = 0.6;
+
+// Base chance (%) of a foul being booked
+pub const BASE_YELLOW_CHANCE: f32 = 10.0;
+
+// Extra booking chance (%) per point of aggression
+pub const AGGRESSION_YELLOW_FACTOR: f32 = 0.15;
+
+// Extra booking chance (%) for players with the Destroy instruction
+pub const DESTROY_YELLOW_BONUS: f32 = 8.0;
+
+// Passive players are booked less
+pub const PASSIVE_YELLOW_FACTOR: f32 = 0.5;
+
+// Chance (%) of a foul being a reckless challenge that can be a straight red
+pub const RECKLESS_CHALLENGE_CHANCE: u8 = 10;
+
+// Chance (%) of a foul being a reckless challenge for players with the Destroy instruction
+pub const DESTROY_RECKLESS_CHALLENGE_CHANCE: u8 = 20;
+
+// Chance (%) of a foul being a reckless challenge for players with the Passive instruction
+pub const PASSIVE_RECKLESS_CHALLENGE_CHANCE: u8 = 5;
+
+// A match can't go on with less players, further cards stay as bookings
+pub const MIN_PLAYERS_ON_PITCH: usize = 7;
+
+// Base chance (%) of a foul on an attacker being inside the box
+pub const BASE_PENALTY_CHANCE: f32 = 4.0;
+
+// Extra penalty chance (%) per point of aggression of the defender
+pub const AGGRESSION_PENALTY_FACTOR: f32 = 0.04;
+
+// Extra penalty chance (%) per point of dribbling of the attacker
+pub const DRIBBLING_PENALTY_FACTOR: f32 = 0.06;
+
+pub fn foul_chance(
+    aggression: u8,
+    style: &DefensiveInstruction,
+    booked: bool,
+    losing_late: bool
+) -> f32 {
+    let mut chance = BASE_FOUL_CHANCE + (aggression as f32) * AGGRESSION_FOUL_FACTOR;
+
+    match style {
+        DefensiveInstruction::Destroy => {
+            chance += DESTROY_FOUL_BONUS;
+        }
+        DefensiveInstruction::Passive => {
+            chance *= PASSIVE_FOUL_FACTOR;
+        }
+        _ => {}
+    }
+
+    if losing_late {
+        chance += LOSING_LATE_FOUL_BONUS;
+    }
+
+    if booked {
+        chance *= BOOKED_FOUL_FACTOR;
+    }
+
+    chance.clamp(0.0, 60.0)
+}
+
+pub fn yellow_chance(aggression: u8, style: &DefensiveInstruction) -> f32 {
+    let chance = BASE_YELLOW_CHANCE + (aggression as f32) * AGGRESSION_YELLOW_FACTOR;
+
+    let chance = match style {
+        DefensiveInstruction::Destroy => chance + DESTROY_YELLOW_BONUS,
+        DefensiveInstruction::Passive => chance * PASSIVE_YELLOW_FACTOR,
+        _ => chance,
+    };
+
+    chance.clamp(0.0, 60.0)
+}
+
+/// Chance (%) of a foul being a reckless challenge that can end in a straight red
+pub fn reckless_chance(style: &DefensiveInstruction) -> u8 {
+    match style {
+        DefensiveInstruction::Destroy => DESTROY_RECKLESS_CHALLENGE_CHANCE,
+        DefensiveInstruction::Passive => PASSIVE_RECKLESS_CHALLENGE_CHANCE,
+        _ => RECKLESS_CHALLENGE_CHANCE,
+    }
+}
+
+/// Chance (%) of a foul on an attacker being a penalty, reckless defenders
+/// and good dribblers going into the box give away more
+pub fn penalty_chance(aggression: u8, dribbling: u8) -> f32 {
+    (
+        BASE_PENALTY_CHANCE +
+        (aggression as f32) * AGGRESSION_PENALTY_FACTOR +
+        (dribbling as f32) * DRIBBLING_PENALTY_FACTOR
+    ).clamp(0.0, 30.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_penalty_chance() {
+        assert!(penalty_chance(99, 50) > penalty_chance(20, 50));
+        assert!(penalty_chance(50, 99) > penalty_chance(50, 20));
+    }
+
+    #[test]
+    fn test_aggression_increases_fouls() {
+        let normal = DefensiveInstruction::Normal;
+
+        assert!(foul_chance(99, &normal, false, false) > foul_chance(20, &normal, false, false));
+        assert!(yellow_chance(99, &normal) > yellow_chance(20, &normal));
+    }
+
+    #[test]
+    fn test_match_state_changes_fouls() {
+        let normal = DefensiveInstruction::Normal;
+        let base = foul_chance(55, &normal, false, false);
+
+        assert!(foul_chance(55, &DefensiveInstruction::Destroy, false, false) > base, "Destroy must foul more");
+        assert!(foul_chance(55, &DefensiveInstruction::Passive, false, false) < base, "Passive must foul less");
+        assert!(foul_chance(55, &normal, false, true) > base, "Losing late must foul more");
+        assert!(foul_chance(55, &normal, true, false) < base, "Booked players must foul less");
+    }
+
+    #[test]
+    fn test_defensive_style_changes_cards() {
+        let base = yellow_chance(55, &DefensiveInstruction::Normal);
+
+        assert!(yellow_chance(55, &DefensiveInstruction::Destroy) > base);
+        assert!(yellow_chance(55, &DefensiveInstruction::Passive) < base);
+        assert!(
+            reckless_chance(&DefensiveInstruction::Destroy) >
+                reckless_chance(&DefensiveInstruction::Passive)
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use crate::logics::game::tactics::tempo_level;
+use crate::models::game::save_type::SaveType;
+use crate::models::game::tactics::{ Pressing, Tempo };
+
+// Share (%) of the shots that don't go in which are on target, the rest go wide
+pub const ON_TARGET_CHANCE: u8 = 60;
+
+// Long kick chance (%) moved by every level of tempo and by the pressing of the opponent
+pub const TEMPO_LONG_KICK_SHIFT: f32 = 15.0;
+pub const PRESSING_LONG_KICK_SHIFT: f32 = 10.0;
+
+/// Chance (0 - 1) of holding a shot, harder shots (higher xG) are harder to hold.
+/// `performance` is the energy factor of the goalkeeper
+pub fn catch_chance(handling: u8, performance: f32, xg: f32) -> f32 {
+    ((handling as f32) / 100.0 * performance * 0.5 - xg).clamp(0.1, 0.8)
+}
+
+/// Chance (0 - 1) of spilling a shot the goalkeeper didn't hold
+pub fn fumble_chance(handling: u8, performance: f32) -> f32 {
+    ((100.0 - (handling as f32) * performance) / 100.0 * 0.2).clamp(0.02, 0.2)
+}
+
+/// Share (0 - 1) of parries pushed out for a corner, quick reactions get a hand
+/// to the ball and put it out of danger
+pub fn parry_corner_chance(reflexes: u8, performance: f32) -> f32 {
+    ((reflexes as f32) / 100.0 * performance * 1.2).clamp(0.3, 0.85)
+}
+
+/// Save of a shot on target from the rolls (0 - 1) of the catch, the fumble and the parry
+pub fn save_type(
+    handling: u8,
+    reflexes: u8,
+    performance: f32,
+    xg: f32,
+    rolls: [f32; 3]
+) -> SaveType {
+    if rolls[0] < catch_chance(handling, performance, xg) {
+        SaveType::Catch
+    } else if rolls[1] < fumble_chance(handling, performance) {
+        SaveType::Fumble
+    } else if rolls[2] < parry_corner_chance(reflexes, performance) {
+        SaveType::ParryCorner
+    } else {
+        SaveType::ParryInPlay
+    }
+}
+
+/// Chance (%) of the goalkeeper kicking long instead of building up from the back.
+/// Good kickers and fast teams go long, a high press leaves no room to play short
+pub fn long_kick_chance(kicking: u8, tempo: &Tempo, opponent_pressing: &Pressing) -> f32 {
+    let pressing = match opponent_pressing {
+        Pressing::Low => -PRESSING_LONG_KICK_SHIFT,
+        Pressing::Medium => 0.0,
+        Pressing::High => PRESSING_LONG_KICK_SHIFT,
+    };
+
+    (20.0 +
+        (kicking as f32) * 0.35 +
+        (tempo_level(tempo) as f32) * TEMPO_LONG_KICK_SHIFT +
+        pressing).clamp(5.0, 90.0)
+}
+
+/// Chance (%) of the goalkeeper coming off the line to claim a cross
+pub fn claim_chance(handling: u8, height_cm: u8, performance: f32) -> f32 {
+    ((5.0 + ((handling as f32) - 50.0) * 0.15 + ((height_cm as f32) - 185.0) * 0.3) * performance).clamp(
+        0.0,
+        20.0
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handling_holds_more_shots() {
+        assert!(catch_chance(90, 1.0, 0.05) > catch_chance(40, 1.0, 0.05));
+        assert!(catch_chance(90, 1.0, 0.5) < catch_chance(90, 1.0, 0.05), "Hard shots are parried");
+        assert!(fumble_chance(90, 1.0) < fumble_chance(40, 1.0));
+        assert!(fumble_chance(90, 0.7) > fumble_chance(90, 1.0), "Tired goalkeepers spill more");
+    }
+
+    #[test]
+    fn test_save_type_follows_the_rolls() {
+        assert_eq!(save_type(80, 80, 1.0, 0.05, [0.0, 1.0, 1.0]), SaveType::Catch);
+        assert_eq!(save_type(80, 80, 1.0, 0.05, [1.0, 0.0, 1.0]), SaveType::Fumble);
+        assert_eq!(save_type(80, 80, 1.0, 0.05, [1.0, 1.0, 0.0]), SaveType::ParryCorner);
+        assert_eq!(save_type(80, 80, 1.0, 0.05, [1.0, 1.0, 1.0]), SaveType::ParryInPlay);
+        assert!(parry_corner_chance(90, 1.0) > parry_corner_chance(40, 1.0));
+    }
+
+    #[test]
+    fn test_long_kick_chance() {
+        let base = long_kick_chance(60, &Tempo::Normal, &Pressing::Medium);
+
+        assert!(long_kick_chance(90, &Tempo::Normal, &Pressing::Medium) > base);
+        assert!(long_kick_chance(60, &Tempo::Fast, &Pressing::Medium) > base);
+        assert!(long_kick_chance(60, &Tempo::Slow, &Pressing::Low) < base);
+        assert!(long_kick_chance(60, &Tempo::Normal, &Pressing::High) > base);
+        assert!(long_kick_chance(0, &Tempo::Slow, &Pressing::Low) >= 5.0);
+    }
+}
@@ -0,0 +1,49 @@
+// Base chance (%) of a player getting injured in a physical duel
+pub const BASE_INJURY_CHANCE: f32 = 0.05;
+
+// Extra injury chance (%) per point of aggression of the opponent
+pub const AGGRESSION_INJURY_FACTOR: f32 = 0.002;
+
+// Extra injury chance (%) per point of energy the victim has lost
+pub const FATIGUE_INJURY_FACTOR: f32 = 0.003;
+
+// Fouls are far more dangerous than clean challenges
+pub const FOUL_INJURY_FACTOR: f32 = 8.0;
+
+// Chance (%) of an injury being hard and forcing the player off
+pub const HARD_INJURY_CHANCE: u8 = 30;
+
+// Skills kept by a player playing on with a soft injury
+pub const SOFT_INJURY_SKILL_FACTOR: f32 = 0.85;
+
+// Days out returned to the backend for each kind of injury (inclusive ranges)
+pub const SOFT_INJURY_DAYS: (u8, u8) = (1, 7);
+pub const HARD_INJURY_DAYS: (u8, u8) = (14, 90);
+
+/// Chance (%) of the victim of a challenge getting injured, grows with the
+/// aggression of the opponent and the energy the victim has already lost
+pub fn injury_chance(aggression: u8, energy: f32, foul: bool) -> f32 {
+    let fatigue = (100.0 - energy).max(0.0);
+    let mut chance =
+        BASE_INJURY_CHANCE +
+        (aggression as f32) * AGGRESSION_INJURY_FACTOR +
+        fatigue * FATIGUE_INJURY_FACTOR;
+
+    if foul {
+        chance *= FOUL_INJURY_FACTOR;
+    }
+
+    chance.clamp(0.0, 25.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_injury_chance_grows_with_aggression_and_fatigue() {
+        assert!(injury_chance(99, 100.0, false) > injury_chance(20, 100.0, false));
+        assert!(injury_chance(50, 30.0, false) > injury_chance(50, 100.0, false));
+        assert!(injury_chance(50, 80.0, true) > injury_chance(50, 80.0, false));
+    }
+}
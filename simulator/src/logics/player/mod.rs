@@ -3,4 +3,14 @@ pub mod generate_player_skills;
 pub mod generate_goalkeeper_skills;
 pub mod generate_random_country;
 pub mod names;
-pub mod actions;
\ No newline at end of file
+pub mod actions;
+pub mod fatigue;
+pub mod fouls;
+pub mod injuries;
+pub mod offside;
+pub mod passing;
+pub mod rating;
+pub mod tackling;
+pub mod aerial;
+pub mod shooting;
+pub mod goalkeeping;
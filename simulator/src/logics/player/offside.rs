@@ -0,0 +1,121 @@
+use crate::models::player::position::Position;
+use crate::models::game::tactics::DefensiveLine;
+
+// Base chance (%) of a forward run being caught offside
+pub const BASE_OFFSIDE_CHANCE: f32 = 6.0;
+
+// Extra offside chance (%) for every defender playing the offside trap
+pub const TRAP_OFFSIDE_FACTOR: f32 = 5.0;
+
+// Offside chance (%) lost per point of speed the receiver has over the defensive line
+pub const SPEED_OFFSIDE_FACTOR: f32 = 0.3;
+
+// Through balls are played to feet and are flagged less than long balls
+pub const THROUGH_BALL_OFFSIDE_FACTOR: f32 = 0.5;
+
+// Chance (%) per point of speed over the defensive line of a receiver beating the trap
+pub const TRAP_BEATEN_FACTOR: f32 = 1.5;
+
+// Extra offside chance (%) against a high line, a deep line catches less
+pub const HIGH_LINE_OFFSIDE_BONUS: f32 = 4.0;
+pub const DEEP_LINE_OFFSIDE_BONUS: f32 = -3.0;
+
+// Chance (%) per point of speed over the line of running in behind a high line
+// that isn't playing the offside trap
+pub const HIGH_LINE_BEATEN_FACTOR: f32 = 0.75;
+
+/// Players holding the defensive line, the ones who can play the offside trap
+pub fn is_defensive_line(position: &Position) -> bool {
+    matches!(position, Position::Defender | Position::Left_Back | Position::Right_Back)
+}
+
+/// Receivers that can be caught offside. Through balls only look for the forwards,
+/// long balls also for midfielders running from deep
+pub fn can_be_offside(position: &Position, through_ball: bool) -> bool {
+    match position {
+        Position::Striker | Position::Left_Wing | Position::Right_Wing => true,
+        Position::Attacking_Midfield | Position::Left_Midfield | Position::Right_Midfield => {
+            !through_ball
+        }
+        _ => false,
+    }
+}
+
+/// Chance (%) of a pass to a forward run being flagged offside, grows with the players
+/// in the offside trap and a high line and falls when the receiver is faster than the line
+pub fn offside_chance(
+    receiver_speed: f32,
+    line_speed: f32,
+    trap_size: usize,
+    line: &DefensiveLine,
+    through_ball: bool
+) -> f32 {
+    let line_bonus = match line {
+        DefensiveLine::Deep => DEEP_LINE_OFFSIDE_BONUS,
+        DefensiveLine::Normal => 0.0,
+        DefensiveLine::High => HIGH_LINE_OFFSIDE_BONUS,
+    };
+
+    let mut chance =
+        BASE_OFFSIDE_CHANCE +
+        (trap_size as f32) * TRAP_OFFSIDE_FACTOR +
+        line_bonus -
+        (receiver_speed - line_speed) * SPEED_OFFSIDE_FACTOR;
+
+    if through_ball {
+        chance *= THROUGH_BALL_OFFSIDE_FACTOR;
+    }
+
+    chance.clamp(0.0, 60.0)
+}
+
+/// Chance (%) of an onside receiver running past an offside trap or in behind
+/// a high line, only fast receivers beat them
+pub fn trap_beaten_chance(
+    receiver_speed: f32,
+    line_speed: f32,
+    trap_size: usize,
+    line: &DefensiveLine
+) -> f32 {
+    let factor = if trap_size > 0 {
+        TRAP_BEATEN_FACTOR
+    } else if *line == DefensiveLine::High {
+        HIGH_LINE_BEATEN_FACTOR
+    } else {
+        return 0.0;
+    };
+
+    ((receiver_speed - line_speed) * factor).clamp(0.0, 50.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offside_trap_catches_more() {
+        let normal = DefensiveLine::Normal;
+
+        assert!(offside_chance(70.0, 70.0, 4, &normal, false) > offside_chance(70.0, 70.0, 0, &normal, false));
+        assert!(offside_chance(70.0, 70.0, 2, &normal, true) < offside_chance(70.0, 70.0, 2, &normal, false));
+    }
+
+    #[test]
+    fn test_fast_receivers_beat_the_trap() {
+        let normal = DefensiveLine::Normal;
+
+        assert!(offside_chance(90.0, 60.0, 4, &normal, false) < offside_chance(60.0, 60.0, 4, &normal, false));
+        assert!(trap_beaten_chance(90.0, 60.0, 4, &normal) > 0.0);
+        assert_eq!(trap_beaten_chance(50.0, 60.0, 4, &normal), 0.0);
+        assert_eq!(trap_beaten_chance(90.0, 60.0, 0, &normal), 0.0);
+    }
+
+    #[test]
+    fn test_defensive_line() {
+        assert!(
+            offside_chance(70.0, 70.0, 0, &DefensiveLine::High, false) >
+                offside_chance(70.0, 70.0, 0, &DefensiveLine::Deep, false)
+        );
+        assert!(trap_beaten_chance(90.0, 60.0, 0, &DefensiveLine::High) > 0.0);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::models::player::position::Position;
+
+// Marked receivers are picked less often
+pub const MARKED_PASS_FACTOR: f32 = 0.4;
+
+// Every teammate keeps a small chance so a pass always finds someone
+pub const MIN_PASS_WEIGHT: f32 = 0.5;
+
+/// Preference of the passer's matrix for a receiver playing at `receiver`
+pub fn pass_weight(matrix: &[(Position, u8)], receiver: &Position) -> u8 {
+    matrix
+        .iter()
+        .find(|(position, _)| position == receiver)
+        .map(|(_, weight)| *weight)
+        .unwrap_or(0)
+}
+
+/// A receiver is marked when their direct opponent defends better than they can
+/// get free, `marking` is the marker's defense already scaled by their energy
+pub fn is_marked(marking: f32, speed: u8, vision: u8) -> bool {
+    marking * 2.0 >= (speed as f32) + (vision as f32)
+}
+
+/// Final weight of a receiver, unwanted receivers only get the minimum weight
+pub fn receiver_weight(preference: u8, marked: bool) -> f32 {
+    let mut weight = preference as f32;
+
+    if marked {
+        weight *= MARKED_PASS_FACTOR;
+    }
+
+    weight.max(MIN_PASS_WEIGHT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pass_weight() {
+        let matrix = Position::Right_Back.passes();
+
+        assert!(
+            pass_weight(matrix, &Position::Left_Back) > pass_weight(matrix, &Position::Right_Midfield)
+        );
+        assert_eq!(pass_weight(matrix, &Position::Right_Back), 0);
+    }
+
+    #[test]
+    fn test_marked_receiver_is_less_likely() {
+        assert!(is_marked(80.0, 60, 70));
+        assert!(!is_marked(50.0, 80, 80));
+        assert!(receiver_weight(20, true) < receiver_weight(20, false));
+        assert_eq!(receiver_weight(0, false), MIN_PASS_WEIGHT);
+    }
+}
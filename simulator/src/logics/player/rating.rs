@@ -0,0 +1,67 @@
+use crate::models::player::match_stats::PlayerMatchStats;
+
+// Rating of a player who took part without doing anything remarkable
+pub const BASE_RATING: f32 = 6.0;
+
+// Weight of every contribution in the rating
+pub const PASS_COMPLETED_WEIGHT: f32 = 0.03;
+pub const CONTROL_FAILED_WEIGHT: f32 = -0.1;
+pub const DRIBBLE_WON_WEIGHT: f32 = 0.1;
+pub const DRIBBLE_LOST_WEIGHT: f32 = -0.05;
+pub const TACKLE_WEIGHT: f32 = 0.1;
+pub const SHOT_WEIGHT: f32 = 0.05;
+pub const SHOT_ON_TARGET_WEIGHT: f32 = 0.15;
+pub const GOAL_WEIGHT: f32 = 1.0;
+pub const ASSIST_WEIGHT: f32 = 0.7;
+pub const SAVE_WEIGHT: f32 = 0.3;
+pub const CLAIM_WEIGHT: f32 = 0.1;
+pub const CLEAN_SHEET_WEIGHT: f32 = 0.5;
+pub const YELLOW_CARD_WEIGHT: f32 = -0.5;
+pub const RED_CARD_WEIGHT: f32 = -1.5;
+
+/// Match rating (0 - 10, one decimal) from the weighted contributions of the player
+pub fn match_rating(stats: &PlayerMatchStats) -> f32 {
+    let rating =
+        BASE_RATING +
+        (stats.passes_suc as f32) * PASS_COMPLETED_WEIGHT +
+        ((stats.controls - stats.controls_suc) as f32) * CONTROL_FAILED_WEIGHT +
+        (stats.dribbles_won as f32) * DRIBBLE_WON_WEIGHT +
+        (stats.dribbles_lost as f32) * DRIBBLE_LOST_WEIGHT +
+        (stats.tackles as f32) * TACKLE_WEIGHT +
+        (stats.shoots as f32) * SHOT_WEIGHT +
+        (stats.shoots_suc as f32) * SHOT_ON_TARGET_WEIGHT +
+        (stats.goals as f32) * GOAL_WEIGHT +
+        (stats.assists as f32) * ASSIST_WEIGHT +
+        (stats.saves as f32) * SAVE_WEIGHT +
+        (stats.claims as f32) * CLAIM_WEIGHT +
+        (if stats.clean_sheet { CLEAN_SHEET_WEIGHT } else { 0.0 }) +
+        (stats.yellow_cards as f32) * YELLOW_CARD_WEIGHT +
+        (stats.red_cards as f32) * RED_CARD_WEIGHT;
+
+    (rating.clamp(0.0, 10.0) * 10.0).round() / 10.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quiet_match_gets_the_base_rating() {
+        assert_eq!(match_rating(&PlayerMatchStats::default()), BASE_RATING);
+    }
+
+    #[test]
+    fn test_contributions_move_the_rating() {
+        let scorer = PlayerMatchStats { goals: 2, shoots: 3, shoots_suc: 2, ..Default::default() };
+        let sent_off = PlayerMatchStats { yellow_cards: 1, red_cards: 1, ..Default::default() };
+
+        assert!(match_rating(&scorer) > BASE_RATING + 2.0);
+        assert!(match_rating(&sent_off) < BASE_RATING - 1.5);
+    }
+
+    #[test]
+    fn test_rating_is_capped() {
+        let hat_tricks = PlayerMatchStats { goals: 9, assists: 5, ..Default::default() };
+        assert_eq!(match_rating(&hat_tricks), 10.0);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::models::game::shot_zone::ShotZone;
+
+// Goalkeeper level (average of handling, intuition and reflexes) the xG is measured
+// against, better goalkeepers concede less than the xG of the shots they face
+pub const REFERENCE_KEEPER: f32 = 70.0;
+
+/// xG of an average shot from `zone`. Players shoot a lot in a match, so the values
+/// are lower than in real football to keep the usual number of goals
+pub fn base_xg(zone: &ShotZone) -> f32 {
+    match zone {
+        ShotZone::LongRange => 0.015,
+        ShotZone::EdgeOfBox => 0.035,
+        ShotZone::InsideBox => 0.065,
+        ShotZone::OneOnOne => 0.25,
+    }
+}
+
+/// Quality (0 - 99) of the shooter from `zone`, long shots rely on `shooting`
+/// and close range shots on `finishing`
+pub fn shot_quality(zone: &ShotZone, shooting: u8, finishing: u8, composure: u8) -> f32 {
+    let (shooting_weight, finishing_weight, composure_weight) = match zone {
+        ShotZone::LongRange => (0.7, 0.0, 0.3),
+        ShotZone::EdgeOfBox => (0.45, 0.35, 0.2),
+        ShotZone::InsideBox => (0.0, 0.7, 0.3),
+        ShotZone::OneOnOne => (0.0, 0.6, 0.4),
+    };
+
+    (shooting as f32) * shooting_weight +
+        (finishing as f32) * finishing_weight +
+        (composure as f32) * composure_weight
+}
+
+/// Expected goals of a shot from `zone` by a shooter of `quality`, `fitness` is the
+/// stamina and energy factor of the shooter
+pub fn shot_xg(zone: &ShotZone, quality: f32, fitness: f32) -> f32 {
+    (base_xg(zone) * (0.4 + (quality / 100.0) * 0.8) * fitness).clamp(0.005, 0.95)
+}
+
+/// Chance (0 - 1) of the shot going in against a goalkeeper of level `keeper`
+pub fn goal_chance(xg: f32, keeper: f32) -> f32 {
+    let keeper_factor = (1.0 + (REFERENCE_KEEPER - keeper) / 100.0).clamp(0.6, 1.4);
+
+    (xg * keeper_factor).clamp(0.005, 0.95)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closer_shots_have_more_xg() {
+        let zones = [ShotZone::LongRange, ShotZone::EdgeOfBox, ShotZone::InsideBox, ShotZone::OneOnOne];
+
+        for pair in zones.windows(2) {
+            assert!(shot_xg(&pair[0], 70.0, 1.0) < shot_xg(&pair[1], 70.0, 1.0));
+        }
+    }
+
+    #[test]
+    fn test_zone_weights_the_right_skill() {
+        // A long range specialist against a poacher
+        let shooter = (90, 40, 60);
+        let poacher = (40, 90, 60);
+
+        assert!(
+            shot_quality(&ShotZone::LongRange, shooter.0, shooter.1, shooter.2) >
+                shot_quality(&ShotZone::LongRange, poacher.0, poacher.1, poacher.2)
+        );
+        assert!(
+            shot_quality(&ShotZone::InsideBox, shooter.0, shooter.1, shooter.2) <
+                shot_quality(&ShotZone::InsideBox, poacher.0, poacher.1, poacher.2)
+        );
+    }
+
+    #[test]
+    fn test_goalkeeper_changes_goal_chance() {
+        let xg = shot_xg(&ShotZone::InsideBox, 70.0, 1.0);
+
+        assert!(goal_chance(xg, 90.0) < xg);
+        assert!(goal_chance(xg, 40.0) > xg);
+        assert_eq!(goal_chance(xg, REFERENCE_KEEPER), xg);
+    }
+}
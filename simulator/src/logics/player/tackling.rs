@@ -0,0 +1,29 @@
+use crate::models::player::instructions::DefensiveInstruction;
+
+// Extra defender score in a dribble duel for players with the Destroy instruction
+pub const DESTROY_TACKLE_BONUS: i32 = 6;
+
+// Lower defender score in a dribble duel for players with the Passive instruction
+pub const PASSIVE_TACKLE_PENALTY: i32 = 6;
+
+/// Points added to the defender score of a dribble duel by their defensive style
+pub fn tackle_bonus(style: &DefensiveInstruction) -> i32 {
+    match style {
+        DefensiveInstruction::Destroy => DESTROY_TACKLE_BONUS,
+        DefensiveInstruction::Passive => -PASSIVE_TACKLE_PENALTY,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defensive_style_changes_tackles() {
+        assert_eq!(tackle_bonus(&DefensiveInstruction::Normal), 0);
+        assert_eq!(tackle_bonus(&DefensiveInstruction::Offside), 0);
+        assert!(tackle_bonus(&DefensiveInstruction::Destroy) > 0);
+        assert!(tackle_bonus(&DefensiveInstruction::Passive) < 0);
+    }
+}
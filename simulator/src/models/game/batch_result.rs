@@ -0,0 +1,181 @@
+use serde::{ Deserialize, Serialize };
+use std::collections::HashMap;
+use crate::models::game::game::GameReturn;
+use crate::models::game::match_event::MatchEvent;
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ScorelineCount {
+    pub score: [u8; 2],
+    pub count: u32,
+    pub probability: f32,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct TopScorer {
+    pub player_name: String,
+    pub player_number: u8,
+    pub team_name: String,
+    pub goals: u32,
+    pub goals_per_game: f32,
+}
+
+/// Outcome distribution of the same match simulated many times.
+/// Win/draw/loss are given from the point of view of the first team.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct BatchResult {
+    pub simulations: u32,
+    pub seed: u64,
+    pub win_probability: f32,
+    pub draw_probability: f32,
+    pub loss_probability: f32,
+    pub goals_mean: [f32; 2],
+    pub goals_variance: [f32; 2],
+    pub fouls_mean: [f32; 2],
+    pub yellow_cards_mean: [f32; 2],
+    pub red_cards_mean: [f32; 2],
+    pub tackles_mean: [f32; 2],
+    pub scorelines: Vec<ScorelineCount>,
+    pub top_scorers: Vec<TopScorer>,
+}
+
+/// Running totals of a batch, every worker fills its own and they are merged at the end
+#[derive(Debug, Clone, Default)]
+pub struct BatchStats {
+    pub games: u32,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+    pub goals_sum: [u64; 2],
+    pub goals_square_sum: [u64; 2],
+    pub fouls_sum: [u64; 2],
+    pub yellow_cards_sum: [u64; 2],
+    pub red_cards_sum: [u64; 2],
+    pub tackles_sum: [u64; 2],
+    pub scorelines: HashMap<[u8; 2], u32>,
+    pub scorers: HashMap<(String, String, u8), u32>,
+}
+
+impl BatchStats {
+    pub fn add_game(&mut self, game: &GameReturn, team_names: &[String; 2]) {
+        let result = match &game.game_result {
+            Some(result) => result,
+            None => {
+                return;
+            }
+        };
+        let score = result.score;
+
+        self.games += 1;
+
+        if score[0] > score[1] {
+            self.wins += 1;
+        } else if score[0] == score[1] {
+            self.draws += 1;
+        } else {
+            self.losses += 1;
+        }
+
+        for (i, goals) in score.iter().map(|&goals| goals as u64).enumerate() {
+            self.goals_sum[i] += goals;
+            self.goals_square_sum[i] += goals * goals;
+
+            let stats = &result.teams_stats[i];
+            let opponent_stats = &result.teams_stats[1 - i];
+            self.fouls_sum[i] += stats.fouls as u64;
+            self.yellow_cards_sum[i] += stats.yellow_cards.len() as u64;
+            self.red_cards_sum[i] += stats.red_cards.len() as u64;
+            self.tackles_sum[i] += (opponent_stats.dribbles - opponent_stats.dribbles_suc) as u64;
+        }
+
+        *self.scorelines.entry(score).or_insert(0) += 1;
+
+        for record in &game.events {
+            if let MatchEvent::Goal { scorer, .. } = &record.event {
+                let team_name = team_names[scorer.team as usize].clone();
+                *self.scorers.entry((team_name, scorer.name.clone(), scorer.number)).or_insert(0) += 1;
+            }
+        }
+    }
+
+    pub fn merge(&mut self, other: BatchStats) {
+        self.games += other.games;
+        self.wins += other.wins;
+        self.draws += other.draws;
+        self.losses += other.losses;
+
+        for i in 0..2 {
+            self.goals_sum[i] += other.goals_sum[i];
+            self.goals_square_sum[i] += other.goals_square_sum[i];
+            self.fouls_sum[i] += other.fouls_sum[i];
+            self.yellow_cards_sum[i] += other.yellow_cards_sum[i];
+            self.red_cards_sum[i] += other.red_cards_sum[i];
+            self.tackles_sum[i] += other.tackles_sum[i];
+        }
+
+        for (score, count) in other.scorelines {
+            *self.scorelines.entry(score).or_insert(0) += count;
+        }
+
+        for (scorer, goals) in other.scorers {
+            *self.scorers.entry(scorer).or_insert(0) += goals;
+        }
+    }
+
+    pub fn into_result(self, seed: u64, top_scorers: usize) -> BatchResult {
+        let games = self.games.max(1) as f32;
+
+        let mut goals_mean = [0.0; 2];
+        let mut goals_variance = [0.0; 2];
+        for i in 0..2 {
+            let mean = (self.goals_sum[i] as f32) / games;
+            goals_mean[i] = mean;
+            goals_variance[i] = ((self.goals_square_sum[i] as f32) / games - mean * mean).max(0.0);
+        }
+
+        let per_game = |sums: [u64; 2]| [(sums[0] as f32) / games, (sums[1] as f32) / games];
+
+        let mut scorelines: Vec<ScorelineCount> = self.scorelines
+            .into_iter()
+            .map(|(score, count)| ScorelineCount {
+                score,
+                count,
+                probability: (count as f32) / games,
+            })
+            .collect();
+        scorelines.sort_by(|a, b| b.count.cmp(&a.count).then(a.score.cmp(&b.score)));
+
+        let mut scorers: Vec<TopScorer> = self.scorers
+            .into_iter()
+            .map(|((team_name, player_name, player_number), goals)| TopScorer {
+                player_name,
+                player_number,
+                team_name,
+                goals,
+                goals_per_game: (goals as f32) / games,
+            })
+            .collect();
+        scorers.sort_by(|a, b| {
+            b.goals
+                .cmp(&a.goals)
+                .then(a.team_name.cmp(&b.team_name))
+                .then(a.player_number.cmp(&b.player_number))
+        });
+        scorers.truncate(top_scorers);
+
+        BatchResult {
+            simulations: self.games,
+            seed,
+            win_probability: (self.wins as f32) / games,
+            draw_probability: (self.draws as f32) / games,
+            loss_probability: (self.losses as f32) / games,
+            goals_mean,
+            goals_variance,
+            fouls_mean: per_game(self.fouls_sum),
+            yellow_cards_mean: per_game(self.yellow_cards_sum),
+            red_cards_mean: per_game(self.red_cards_sum),
+            tackles_mean: per_game(self.tackles_sum),
+            scorelines,
+            top_scorers: scorers,
+        }
+    }
+}
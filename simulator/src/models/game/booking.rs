@@ -0,0 +1,31 @@
+use serde::{ Deserialize, Serialize };
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub enum BookingType {
+    Yellow,
+    SecondYellow,
+    Red,
+}
+
+impl BookingType {
+    pub fn is_sending_off(&self) -> bool {
+        matches!(self, BookingType::SecondYellow | BookingType::Red)
+    }
+
+    pub fn log_description(&self) -> &'static str {
+        match self {
+            BookingType::Yellow => "yellow_card.foul",
+            BookingType::SecondYellow => "second_yellow_card.foul",
+            BookingType::Red => "red_card.foul",
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Booking {
+    pub team_index: u8,
+    pub player_name: String,
+    pub player_number: u8,
+    pub minute: u8,
+    pub card: BookingType,
+}
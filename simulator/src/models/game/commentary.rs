@@ -0,0 +1,213 @@
+use serde::{ Deserialize, Serialize };
+use std::collections::HashMap;
+use crate::logics::game::commentary::goal_context;
+use crate::models::game::match_event::{ EventPlayer, EventRecord, MatchEvent, PassOutcome };
+use crate::models::player::position::Position;
+use crate::utils::generate_random_number::{
+    GameRng,
+    create_game_rng,
+    generate_seeded_number_by_range,
+};
+use crate::validators::game::commentary::validate_lang;
+
+/// Commentary of the event with the same `sequence`
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct CommentaryLine {
+    pub sequence: u32,
+    pub minute: u8,
+    pub added_minute: u8,
+    pub text: String,
+}
+
+/// What makes a goal special, commented with its own templates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GoalContext {
+    Equaliser,
+    LateWinner,
+    HatTrick,
+}
+
+impl GoalContext {
+    pub fn key(&self) -> &'static str {
+        match self {
+            GoalContext::Equaliser => "goal.equaliser",
+            GoalContext::LateWinner => "goal.late_winner",
+            GoalContext::HatTrick => "goal.hat_trick",
+        }
+    }
+}
+
+/// Phrasing variants of every event key in a language, read from `src/data/commentary/{lang}.json`
+#[derive(Deserialize, Debug, Clone)]
+#[serde(transparent)]
+pub struct CommentaryTemplates {
+    templates: HashMap<String, Vec<String>>,
+}
+
+impl CommentaryTemplates {
+    pub fn load(lang: &str) -> Result<Self, String> {
+        validate_lang(lang)?;
+
+        let path = format!("src/data/commentary/{}.json", lang);
+        let json_str = std::fs
+            ::read_to_string(&path)
+            .map_err(|_| format!("Unsupported lang: {}", lang))?;
+        let templates: Self = serde_json
+            ::from_str(&json_str)
+            .map_err(|e| format!("Invalid commentary templates for {}: {}", lang, e))?;
+
+        if let Some(key) = templates.templates.keys().find(|k| templates.templates[*k].is_empty()) {
+            return Err(format!("Commentary key {} of {} has no templates", key, lang));
+        }
+
+        Ok(templates)
+    }
+
+    pub fn variants(&self, key: &str) -> Option<&Vec<String>> {
+        self.templates.get(key)
+    }
+}
+
+/// Turns the match events into commentary, one event after another.
+/// Keeps the score and the goals of every scorer to comment goals in their context
+pub struct Commentator {
+    templates: CommentaryTemplates,
+    team_names: [String; 2],
+    score: [u8; 2],
+    // Goals of every scorer by team and shirt number
+    goals: HashMap<(u8, u8), u8>,
+    rng: GameRng,
+}
+
+impl Commentator {
+    /// The same `seed` always picks the same phrasing
+    pub fn new(templates: CommentaryTemplates, team_names: [String; 2], seed: u64) -> Self {
+        Self {
+            templates,
+            team_names,
+            score: [0, 0],
+            goals: HashMap::new(),
+            rng: create_game_rng(seed),
+        }
+    }
+
+    /// Commentary of the whole match
+    pub fn comment_all(&mut self, events: &[EventRecord]) -> Vec<CommentaryLine> {
+        events
+            .iter()
+            .flat_map(|event| self.comment(event))
+            .collect()
+    }
+
+    /// Commentary of the next event, an assisted goal also comments the assist.
+    /// Events without templates in the language are not commented
+    pub fn comment(&mut self, record: &EventRecord) -> Vec<CommentaryLine> {
+        let event = &record.event;
+        let mut key = event.description();
+
+        if let MatchEvent::Goal { scorer, .. } = event {
+            let team = scorer.team as usize;
+            self.score[team] += 1;
+
+            let goals = self.goals.entry((scorer.team, scorer.number)).or_insert(0);
+            *goals += 1;
+
+            if let Some(context) = goal_context(self.score, team, record.minute, *goals) {
+                key = context.key().to_string();
+            }
+        }
+
+        let mut texts = vec![self.text(&key, event.subject(), counterpart(event), event)];
+        if let MatchEvent::Goal { assistant: Some(assistant), scorer, .. } = event {
+            texts.push(self.text("assist.goal", Some(assistant), Some(scorer), event));
+        }
+
+        texts
+            .into_iter()
+            .flatten()
+            .map(|text| CommentaryLine {
+                sequence: record.sequence,
+                minute: record.minute,
+                added_minute: record.added_minute,
+                text,
+            })
+            .collect()
+    }
+
+    /// Picks a variant of `key` and fills in its placeholders
+    fn text(
+        &mut self,
+        key: &str,
+        player: Option<&EventPlayer>,
+        other: Option<&EventPlayer>,
+        event: &MatchEvent
+    ) -> Option<String> {
+        let variants = self.templates.variants(key)?;
+        let pick = generate_seeded_number_by_range(&mut self.rng, 0, (variants.len() - 1) as u8);
+        let mut text = variants[pick as usize].clone();
+
+        let team = match event {
+            MatchEvent::ManagerRule { team, .. } => Some(*team as usize),
+            _ => player.map(|p| p.team as usize),
+        };
+        let (team_name, opponent_name) = match team {
+            Some(team) => (self.team_names[team].as_str(), self.team_names[1 - team].as_str()),
+            None => ("", ""),
+        };
+        let player_name = match event {
+            MatchEvent::ManagerRule { manager, .. } => manager.as_str(),
+            _ => player.map_or("", |p| p.name.as_str()),
+        };
+
+        let values = [
+            ("{player}", player_name.to_string()),
+            ("{other}", other.map_or(String::new(), |p| p.name.clone())),
+            ("{team}", team_name.to_string()),
+            ("{opponent}", opponent_name.to_string()),
+            ("{home}", self.team_names[0].clone()),
+            ("{away}", self.team_names[1].clone()),
+            ("{score}", format!("{}-{}", self.score[0], self.score[1])),
+            ("{minutes}", match event {
+                MatchEvent::AddedTime { minutes } => minutes.to_string(),
+                _ => String::new(),
+            }),
+            ("{position}", match event {
+                MatchEvent::PositionChange { position, .. } => self.position_name(position),
+                _ => String::new(),
+            }),
+        ];
+        for (placeholder, value) in values {
+            text = text.replace(placeholder, &value);
+        }
+
+        Some(text)
+    }
+
+    /// Name of a position in the language, `position.{Position}` in the templates
+    fn position_name(&self, position: &Position) -> String {
+        let position = format!("{:?}", position);
+        match self.templates.variants(&format!("position.{}", position)) {
+            Some(names) => names[0].clone(),
+            None => position.replace('_', " "),
+        }
+    }
+}
+
+/// The other player of an event: the passer or receiver of a pass, the goalkeeper facing a shot,
+/// the crosser, the victim of a foul or the player replaced by a substitute
+fn counterpart(event: &MatchEvent) -> Option<&EventPlayer> {
+    match event {
+        // The receiver is the one caught offside
+        MatchEvent::Pass { passer, outcome: PassOutcome::Offside, .. } => Some(passer),
+        MatchEvent::Pass { receiver, .. } => receiver.as_ref(),
+        // The cross is on the player who dealt with it when there is one
+        MatchEvent::Cross { crosser, player, .. } => player.as_ref().map(|_| crosser),
+        MatchEvent::Shot { goalkeeper, .. } |
+        MatchEvent::Penalty { goalkeeper, .. } |
+        MatchEvent::Corner { goalkeeper, .. } |
+        MatchEvent::ShootoutKick { goalkeeper, .. } => Some(goalkeeper),
+        MatchEvent::Foul { victim, .. } => Some(victim),
+        MatchEvent::Substitution { player_out, .. } => Some(player_out),
+        _ => None,
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{ Deserialize, Serialize };
+use crate::models::player::position::Position;
+
+/// How a free kick is taken depending on where the foul happened
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub enum FreeKickZone {
+    // Close to the box, shot at goal over the wall
+    Direct,
+    // Wide areas, cross into the box
+    Cross,
+    // Own half or midfield, short restart
+    Short,
+    // Awarded to the defending team after an offside, the goalkeeper restarts
+    Indirect,
+}
+
+impl FreeKickZone {
+    /// Zone of a foul committed against a player in `position`
+    pub fn from_position(position: &Position) -> Self {
+        match position {
+            Position::Striker | Position::Attacking_Midfield => FreeKickZone::Direct,
+            | Position::Left_Wing
+            | Position::Right_Wing
+            | Position::Left_Midfield
+            | Position::Right_Midfield => FreeKickZone::Cross,
+            _ => FreeKickZone::Short,
+        }
+    }
+}
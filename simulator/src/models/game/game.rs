@@ -1,32 +1,99 @@
 use serde::{ Deserialize, Serialize };
-use crate::models::game::game_result::GameResult;
+use crate::models::game::game_result::{ GameResult, ManOfTheMatch };
 use crate::models::game::log::Log;
+use crate::models::game::commentary::CommentaryLine;
+use crate::models::game::match_event::{
+    EventPlayer,
+    EventRecord,
+    MatchEvent,
+    ShootoutOutcome,
+    SubstitutionReason,
+};
 use crate::models::game::team::Team;
+use crate::models::game::substitution::{ Substitution, HALF_TIME_MINUTE };
+use crate::models::game::match_mode::{ MatchMode, MatchOutcome };
 use crate::models::player::position::Position;
 use crate::models::player::action_selector::ActionSelector;
 
+use crate::logics::player::fatigue::energy_performance_factor;
+use crate::logics::game::tactics::actions_per_minute;
+use crate::logics::game::shootout::{
+    is_shootout_decided,
+    shootout_score_chance,
+    SHOOTOUT_SAVE_SHARE,
+};
+use crate::logics::game::stoppage_time::{
+    added_time,
+    can_be_checked,
+    stoppage_time,
+    VAR_CHECK_ADDED_TIME,
+    VAR_CHECK_CHANCE,
+};
+
 use crate::validators::game::lineup::validate_lineup;
+use crate::validators::game::substitutions::validate_substitutions;
+use crate::validators::game::manager_rules::validate_manager_rules;
+use crate::models::game::manager_rule::{ RuleAction, RuleState };
 
-use crate::utils::generate_random_number::generate_number_by_range;
+use crate::utils::generate_random_number::{
+    GameRng,
+    create_game_rng,
+    generate_seed,
+    generate_seeded_number_by_range,
+};
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Game {
     pub teams: [Team; 2],
     pub game_result: GameResult,
-    pub logs: Vec<Log>,
+    pub events: Vec<EventRecord>,
     pub ball_possession: [u8; 2],
     pub minute: u8,
+    // Minute of stoppage time being played, 0 in regular time
+    pub added_minute: u8,
     pub action: i32,
+    pub seed: u64,
+    #[serde(default)]
+    pub mode: MatchMode,
+    #[serde(skip, default = "default_game_rng")]
+    pub rng: GameRng,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct GameReturn {
+    pub events: Vec<EventRecord>,
+    // Legacy logs built from the events, only sent when the request asks for them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub logs: Vec<Log>,
+    // Commentary of the events, only sent when the request asks for a language
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub commentary: Vec<CommentaryLine>,
     pub game_result: Option<GameResult>,
+    pub man_of_the_match: Option<ManOfTheMatch>,
+    pub seed: u64,
+}
+
+fn default_game_rng() -> GameRng {
+    create_game_rng(generate_seed())
 }
 
 impl Game {
-    pub fn create_game(teams: [Team; 2]) -> Result<GameReturn, String> {
+    /// Same teams and same seed always produce the same events and score.
+    /// When no seed is provided a random one is generated and returned in `GameReturn`.
+    /// League match with the legacy logs, the tests read them
+    #[cfg(test)]
+    pub fn create_game(teams: [Team; 2], seed: Option<u64>) -> Result<GameReturn, String> {
+        Self::create_game_with_mode(teams, seed, MatchMode::League, true)
+    }
+
+    /// Same as `create_game`, knockout draws go to extra time and a penalty shootout.
+    /// The legacy logs are only built when `legacy_logs` is set
+    pub fn create_game_with_mode(
+        teams: [Team; 2],
+        seed: Option<u64>,
+        mode: MatchMode,
+        legacy_logs: bool
+    ) -> Result<GameReturn, String> {
         // Validate lineup for each team
         for (i, team) in teams.iter().enumerate() {
             let positions: Vec<Position> = team.players
@@ -34,6 +101,17 @@ impl Game {
                 .map(|p| p.position.clone())
                 .collect();
             validate_lineup(positions).map_err(|e| format!("Team {} lineup error: {}", i + 1, e))?;
+            validate_substitutions(
+                &team.substitutions,
+                team.players.len(),
+                team.bench_players.len(),
+                mode.last_minute()
+            ).map_err(|e| format!("Team {} substitution error: {}", i + 1, e))?;
+            validate_manager_rules(
+                &team.manager_rules,
+                team.players.len(),
+                team.bench_players.len()
+            ).map_err(|e| format!("Team {} manager rule error: {}", i + 1, e))?;
         }
 
         // Apply card boosts, auras, and country synergy
@@ -42,32 +120,44 @@ impl Game {
             team.apply_card_boosts();
             team.apply_auras();
             team.apply_country_chemical(); // assuming you renamed apply_country_synergy
+
+            for player in &mut team.players {
+                player.match_stats.enter(0);
+            }
         }
 
-        //? Needs to create a system for define the kickers of the team
+        let seed = seed.unwrap_or_else(generate_seed);
 
         // Create game
         let mut game = Self {
             teams,
             game_result: GameResult::create(),
-            logs: Vec::new(),
+            events: Vec::new(),
             ball_possession: [0, 0],
             minute: 255,
+            added_minute: 0,
             action: 0,
+            seed,
+            mode,
+            rng: create_game_rng(seed),
         };
 
-        // Initial log
-        game.logs.push(Log {
-            minute: game.minute,
-            player_name: "game".to_string(),
-            description: "game.start".to_string(),
-            player_number: 0,
-            team_name: "game".to_string(),
-        });
+        // Initial event
+        EventRecord::push(&mut game.events, game.minute, MatchEvent::Start);
 
         game.play_full_match();
 
-        Ok(GameReturn { logs: game.logs, game_result: Some(game.game_result) })
+        let man_of_the_match = game.game_result.man_of_the_match();
+        let logs = if legacy_logs { game.legacy_logs() } else { Vec::new() };
+
+        Ok(GameReturn {
+            events: game.events,
+            logs,
+            commentary: Vec::new(),
+            game_result: Some(game.game_result),
+            man_of_the_match,
+            seed: game.seed,
+        })
     }
 
     pub fn start_match(&mut self) {
@@ -75,91 +165,393 @@ impl Game {
         self.action = 0;
 
         let team_with_ball = 0 as u8;
-        let random_player = generate_number_by_range(0, (self.teams[0].players.len() - 1) as u8);
+        let random_player = generate_seeded_number_by_range(
+            &mut self.rng,
+            0,
+            (self.teams[0].players.len() - 1) as u8
+        );
 
         self.ball_possession = [team_with_ball, random_player];
         let last_pass_player = [team_with_ball, random_player];
 
-        let current_player = &self.teams[0].players[self.ball_possession[1] as usize];
-
-        let log = Log {
-            minute: self.minute,
-            player_name: current_player.name.clone(),
-            description: format!("success.ball"),
-            player_number: current_player.number,
-            team_name: self.teams[0].name.clone(),
-        };
-
-        self.logs.push(log);
+        let player = EventPlayer::new(&self.teams, 0, self.ball_possession[1] as usize);
+        EventRecord::push(&mut self.events, self.minute, MatchEvent::KickOff { player });
     }
 
     pub fn start_half_time(&mut self) {
         self.minute = 0;
         self.action = 0;
 
-        let team_with_ball = 0 as u8;
-        let random_player = generate_number_by_range(0, (self.teams[1].players.len() - 1) as u8);
+        let team_with_ball = 1 as u8;
+        let random_player = generate_seeded_number_by_range(
+            &mut self.rng,
+            0,
+            (self.teams[1].players.len() - 1) as u8
+        );
         self.ball_possession = [team_with_ball, random_player];
 
-        let current_player = &self.teams[1].players[self.ball_possession[1] as usize];
-
-        let log = Log {
-            minute: self.minute,
-            player_name: current_player.name.clone(),
-            description: format!("The second time starts — {} has the ball.", current_player.name),
-            player_number: current_player.number,
-            team_name: self.teams[1].name.clone(),
-        };
-
-        self.logs.push(log);
+        let player = EventPlayer::new(&self.teams, 1, self.ball_possession[1] as usize);
+        EventRecord::push(&mut self.events, self.minute, MatchEvent::HalfTime { player });
     }
 
     pub fn play_first_half(&mut self) {
         self.start_match();
         let mut last_pass_player = self.ball_possession.clone();
+        let first_event = self.events.len();
 
         for minute in 0..45 {
             self.minute = minute;
-
-            for _ in 0..5 {
-                // 5 actions per minute
-                ActionSelector::select_and_execute(
-                    &mut self.teams,
-                    &mut self.ball_possession,
-                    &mut last_pass_player,
-                    self.minute,
-                    &mut self.logs,
-                    &mut self.game_result,
-                );
-                self.action += 1;
-            }
+            self.apply_substitutions(false);
+            self.play_minute(&mut last_pass_player);
         }
+
+        self.minute = 45;
+        self.play_stoppage_time(first_event, &mut last_pass_player);
     }
 
     pub fn play_second_half(&mut self) {
         self.start_half_time();
         let mut last_pass_player = self.ball_possession.clone();
+        let first_event = self.events.len();
 
         for minute in 45..91 {
             self.minute = minute;
+            self.apply_substitutions(minute == HALF_TIME_MINUTE);
+            self.play_minute(&mut last_pass_player);
+        }
+
+        self.play_stoppage_time(first_event, &mut last_pass_player);
+    }
+
+    /// Two halves of 15 minutes, each one with its own stoppage time
+    pub fn play_extra_time(&mut self) {
+        for (team_with_ball, minutes) in [(0, 91..106), (1, 106..121)] {
+            self.start_extra_time_half(team_with_ball);
+            let mut last_pass_player = self.ball_possession.clone();
+            let first_event = self.events.len();
+
+            for minute in minutes {
+                self.minute = minute;
+                self.apply_substitutions(false);
+                self.play_minute(&mut last_pass_player);
+            }
+
+            self.play_stoppage_time(first_event, &mut last_pass_player);
+        }
+    }
+
+    fn start_extra_time_half(&mut self, team_with_ball: usize) {
+        let random_player = generate_seeded_number_by_range(
+            &mut self.rng,
+            0,
+            (self.teams[team_with_ball].players.len() - 1) as u8
+        );
+        self.ball_possession = [team_with_ball as u8, random_player];
+
+        let player = EventPlayer::new(&self.teams, team_with_ball, random_player as usize);
+        EventRecord::push(&mut self.events, self.minute, MatchEvent::ExtraTime { player });
+    }
+
+    /// Penalty shootout of a knockout draw. Both teams kick in their shootout order with
+    /// as many kickers as the shorter team, five kicks each and then sudden death
+    pub fn play_penalty_shootout(&mut self) {
+        let mut orders = [self.teams[0].shootout_order(), self.teams[1].shootout_order()];
+        let kickers = orders[0].len().min(orders[1].len());
+        for order in orders.iter_mut() {
+            order.truncate(kickers);
+        }
+
+        EventRecord::push(&mut self.events, self.minute, MatchEvent::ShootoutStart);
+
+        // Coin toss for the team kicking first
+        let first = generate_seeded_number_by_range(&mut self.rng, 0, 1) as usize;
+
+        let mut score = [0u8; 2];
+        let mut kicks = [0u8; 2];
+
+        'shootout: loop {
+            for team_id in [first, 1 - first] {
+                let taker = orders[team_id][(kicks[team_id] as usize) % kickers];
+                if self.shootout_kick(team_id, taker) {
+                    score[team_id] += 1;
+                }
+                kicks[team_id] += 1;
+
+                if is_shootout_decided(score, kicks) {
+                    break 'shootout;
+                }
+            }
+        }
+
+        self.game_result.outcome = MatchOutcome::Penalties { score };
+    }
+
+    /// One kick of the shootout, the taker's finishing and composure against
+    /// the intuition and reflexes of the goalkeeper
+    fn shootout_kick(&mut self, team_id: usize, taker: usize) -> bool {
+        let opponent_team = if team_id == 0 { 1 } else { 0 };
+
+        let shooter = &self.teams[team_id].players[taker];
+        let goalkeeper_index = self.teams[opponent_team].players
+            .iter()
+            .position(|p| p.current_position == Position::Goalkeeper)
+            .unwrap_or(0);
+        let goalkeeper = &self.teams[opponent_team].players[goalkeeper_index];
+
+        let shot =
+            ((shooter.skills.finishing as f32) * 0.6 + (shooter.skills.composure as f32) * 0.4) *
+            energy_performance_factor(shooter.energy);
+        let save =
+            ((goalkeeper.skills.intuition as f32) * 0.5 + (goalkeeper.skills.reflexes as f32) * 0.5) *
+            energy_performance_factor(goalkeeper.energy);
+
+        let score_pct = (shootout_score_chance(shot, save) * 100.0).round() as u8;
+        let roll = generate_seeded_number_by_range(&mut self.rng, 0, 99);
+
+        let scored = roll < score_pct;
+        let outcome = if scored {
+            ShootoutOutcome::Scored
+        } else if generate_seeded_number_by_range(&mut self.rng, 0, 99) < SHOOTOUT_SAVE_SHARE {
+            ShootoutOutcome::Saved
+        } else {
+            ShootoutOutcome::Missed
+        };
+
+        EventRecord::push(&mut self.events, self.minute, MatchEvent::ShootoutKick {
+            taker: EventPlayer::new(&self.teams, team_id, taker),
+            goalkeeper: EventPlayer::new(&self.teams, opponent_team, goalkeeper_index),
+            outcome,
+        });
+
+        scored
+    }
+
+    /// Plays the actions of the current minute, events in stoppage time get the added minute
+    fn play_minute(&mut self, last_pass_player: &mut [u8; 2]) {
+        let first_event = self.events.len();
+        self.apply_manager_rules();
+        self.drain_minute();
+
+        // The tempo of both teams sets how many actions fit in a minute
+        let actions = actions_per_minute([&self.teams[0].tactics.tempo, &self.teams[1].tactics.tempo]);
+        for _ in 0..actions {
+            ActionSelector::select_and_execute(
+                &mut self.teams,
+                &mut self.ball_possession,
+                last_pass_player,
+                self.minute,
+                &mut self.events,
+                &mut self.game_result,
+                &mut self.rng,
+            );
+            self.action += 1;
+        }
 
-            for _ in 0..5 {
-                // 5 actions per minute
-                ActionSelector::select_and_execute(
-                    &mut self.teams,
-                    &mut self.ball_possession,
-                    &mut last_pass_player,
-                    self.minute,
-                    &mut self.logs,
-                    &mut self.game_result,
-                );
-                self.action += 1;
+        for event in &mut self.events[first_event..] {
+            event.added_minute = self.added_minute;
+        }
+    }
+
+    /// Announces and plays the stoppage time of a half from the time lost by the events
+    /// since `first_event`, goals can also be delayed by a VAR check
+    fn play_stoppage_time(&mut self, first_event: usize, last_pass_player: &mut [u8; 2]) {
+        let mut lost_minutes = 0.0;
+        for i in first_event..self.events.len() {
+            let event = &self.events[i].event;
+            lost_minutes += added_time(event);
+
+            if
+                can_be_checked(event) &&
+                generate_seeded_number_by_range(&mut self.rng, 0, 100) < VAR_CHECK_CHANCE
+            {
+                lost_minutes += VAR_CHECK_ADDED_TIME;
+            }
+        }
+
+        let added = stoppage_time(lost_minutes);
+        self.game_result.added_time.push(added);
+
+        EventRecord::push(&mut self.events, self.minute, MatchEvent::AddedTime { minutes: added });
+        if let Some(announcement) = self.events.last_mut() {
+            announcement.added_minute = added;
+        }
+
+        for added_minute in 1..=added {
+            self.added_minute = added_minute;
+            self.play_minute(last_pass_player);
+        }
+
+        self.added_minute = 0;
+    }
+
+    /// Applies the scheduled substitutions of both teams for the current minute,
+    /// `half_time` when they are made in the break before the second half
+    pub fn apply_substitutions(&mut self, half_time: bool) {
+        for team_index in 0..2 {
+            let due: Vec<Substitution> = self.teams[team_index].substitutions
+                .iter()
+                .filter(|s| s.minute == self.minute)
+                .cloned()
+                .collect();
+
+            for sub in due {
+                let team = &mut self.teams[team_index];
+                if !team.can_substitute(self.minute, half_time) || !team.is_bench_player_available(sub.player_in) {
+                    continue;
+                }
+
+                let player_out = EventPlayer::new(&self.teams, team_index, sub.player_out as usize);
+                self.teams[team_index].substitute(sub.player_out, sub.player_in, self.minute, half_time);
+
+                EventRecord::push(&mut self.events, self.minute, MatchEvent::Substitution {
+                    player_out,
+                    player_in: EventPlayer::new(&self.teams, team_index, sub.player_out as usize),
+                    reason: SubstitutionReason::Tactical,
+                });
+            }
+        }
+    }
+
+    /// State of the match seen by the manager of `team_index`
+    fn rule_state(&self, team_index: usize) -> RuleState {
+        let opponent = if team_index == 0 { 1 } else { 0 };
+        let team = &self.teams[team_index];
+
+        let energy = if team.players.is_empty() {
+            0.0
+        } else {
+            team.players.iter().map(|p| p.energy).sum::<f32>() / (team.players.len() as f32)
+        };
+
+        RuleState {
+            minute: self.minute,
+            goal_difference: (self.game_result.score[team_index] as i8) -
+            (self.game_result.score[opponent] as i8),
+            red_cards: team.sent_off_players.len() as u8,
+            opponent_red_cards: self.teams[opponent].sent_off_players.len() as u8,
+            injuries: self.game_result.injuries
+                .iter()
+                .filter(|i| (i.team_index as usize) == team_index)
+                .count() as u8,
+            energy,
+        }
+    }
+
+    /// Fires the manager rules of both teams whose conditions hold, every rule fires once.
+    /// Substitutions follow the usual limits and are skipped when they can't be made
+    pub fn apply_manager_rules(&mut self) {
+        for team_index in 0..2 {
+            for rule_index in 0..self.teams[team_index].manager_rules.len() {
+                let rule = &self.teams[team_index].manager_rules[rule_index];
+                if rule.fired || !rule.when.is_met(&self.rule_state(team_index)) {
+                    continue;
+                }
+
+                let actions = rule.then.clone();
+                let team = &mut self.teams[team_index];
+                team.manager_rules[rule_index].fired = true;
+
+                EventRecord::push(&mut self.events, self.minute, MatchEvent::ManagerRule {
+                    team: team_index as u8,
+                    manager: team.player_name.clone(),
+                });
+
+                for action in actions {
+                    self.apply_rule_action(team_index, action);
+                }
             }
         }
     }
 
+    fn apply_rule_action(&mut self, team_index: usize, action: RuleAction) {
+        let team = &mut self.teams[team_index];
+
+        let event = match action {
+            RuleAction::Mentality(mentality) => {
+                team.tactics.mentality = mentality;
+                return;
+            }
+            RuleAction::Pressing(pressing) => {
+                team.tactics.pressing = pressing;
+                return;
+            }
+            RuleAction::Tempo(tempo) => {
+                team.tactics.tempo = tempo;
+                return;
+            }
+            RuleAction::Width(width) => {
+                team.tactics.width = width;
+                return;
+            }
+            RuleAction::DefensiveLine(line) => {
+                team.tactics.defensive_line = line;
+                return;
+            }
+            RuleAction::Substitution { player_out, player_in } => {
+                if !team.can_substitute(self.minute, false) || !team.is_bench_player_available(player_in) {
+                    return;
+                }
+                team.substitutions.retain(|s| s.player_out != player_out);
+                let replaced = EventPlayer::new(&self.teams, team_index, player_out as usize);
+                self.teams[team_index].substitute(player_out, player_in, self.minute, false);
+                MatchEvent::Substitution {
+                    player_out: replaced,
+                    player_in: EventPlayer::new(&self.teams, team_index, player_out as usize),
+                    reason: SubstitutionReason::Tactical,
+                }
+            }
+            RuleAction::Position { player, position } => {
+                let player_index = player as usize;
+                if team.players[player_index].position == Position::Goalkeeper {
+                    return;
+                }
+                team.players[player_index].current_position = position.clone();
+                MatchEvent::PositionChange {
+                    player: EventPlayer::new(&self.teams, team_index, player_index),
+                    position,
+                }
+            }
+        };
+
+        EventRecord::push(&mut self.events, self.minute, event);
+    }
+
+    /// Legacy logs of the match events
+    fn legacy_logs(&self) -> Vec<Log> {
+        let team_names = [self.teams[0].name.clone(), self.teams[1].name.clone()];
+        self.events
+            .iter()
+            .flat_map(|event| event.to_logs(&team_names))
+            .collect()
+    }
+
+    /// Every player gets tired each minute, the team without the ball is pressing
+    pub fn drain_minute(&mut self) {
+        let team_with_ball = self.ball_possession[0] as usize;
+        for (team_index, team) in self.teams.iter_mut().enumerate() {
+            team.drain_minute(team_index != team_with_ball);
+        }
+    }
+
+    fn is_draw(&self) -> bool {
+        self.game_result.score[0] == self.game_result.score[1]
+    }
+
     pub fn play_full_match(&mut self) {
         self.play_first_half();
         self.play_second_half();
+
+        if self.mode == MatchMode::Knockout && self.is_draw() {
+            self.game_result.outcome = MatchOutcome::ExtraTime;
+            self.play_extra_time();
+
+            if self.is_draw() {
+                self.play_penalty_shootout();
+            }
+        }
+
+        self.game_result.record_possession();
+        self.game_result.record_energy(&self.teams);
+        self.game_result.record_players_stats(&self.teams, self.minute);
     }
 }
@@ -1,18 +1,197 @@
 use serde::{ Deserialize, Serialize };
+use crate::models::game::booking::{ Booking, BookingType };
+use crate::models::game::injury::Injury;
+use crate::models::game::match_mode::MatchOutcome;
+use crate::models::game::team::Team;
 use crate::models::game::team_stats::TeamStats;
+use crate::models::player::match_stats::PlayerMatchStats;
+use crate::models::player::position::Position;
+use crate::logics::player::rating::match_rating;
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PlayerEnergy {
+    pub player_name: String,
+    pub player_number: u8,
+    pub energy: f32,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ManOfTheMatch {
+    pub team_index: u8,
+    pub player_name: String,
+    pub player_number: u8,
+    pub rating: f32,
+}
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct GameResult {
     pub score: [u8; 2],
-    //pub teams_stats: [TeamStats; 2],
+    pub teams_stats: [TeamStats; 2],
+    // Energy of every player of the squad when the match ends
+    pub energy: [Vec<PlayerEnergy>; 2],
+    pub bookings: Vec<Booking>,
+    pub injuries: Vec<Injury>,
+    // Stat line of every player who took part in the match
+    pub players_stats: [Vec<PlayerMatchStats>; 2],
+    // Stoppage time played at the end of each half, extra time halves included
+    pub added_time: Vec<u8>,
+    pub outcome: MatchOutcome,
 }
 
 impl GameResult {
     pub fn create() -> Self {
         Self {
             score: [0, 0],
-            //teams_stats: [TeamStats::default(), TeamStats::default()],
+            teams_stats: [TeamStats::default(), TeamStats::default()],
+            energy: [Vec::new(), Vec::new()],
+            bookings: Vec::new(),
+            injuries: Vec::new(),
+            players_stats: [Vec::new(), Vec::new()],
+            added_time: Vec::new(),
+            outcome: MatchOutcome::RegularTime,
         }
     }
-}
 
+    /// Team that won the match, a penalty shootout decides knockout draws
+    pub fn winner(&self) -> Option<usize> {
+        let score = match &self.outcome {
+            MatchOutcome::Penalties { score } => *score,
+            _ => self.score,
+        };
+
+        if score[0] > score[1] {
+            Some(0)
+        } else if score[1] > score[0] {
+            Some(1)
+        } else {
+            None
+        }
+    }
+
+    /// Counts a goal in the score and in the scorers of the team
+    pub fn add_goal(&mut self, team_index: usize, player_number: u8) {
+        self.score[team_index] += 1;
+        self.teams_stats[team_index].add_scorer(player_number);
+    }
+
+    pub fn add_booking(&mut self, booking: Booking) {
+        let stats = &mut self.teams_stats[booking.team_index as usize];
+        match booking.card {
+            BookingType::Yellow => stats.add_card("yellow", booking.player_number),
+            BookingType::SecondYellow => {
+                stats.add_card("yellow", booking.player_number);
+                stats.add_card("red", booking.player_number);
+            }
+            BookingType::Red => stats.add_card("red", booking.player_number),
+        }
+
+        self.bookings.push(booking);
+    }
+
+    pub fn is_booked(&self, team_index: usize, player_name: &str, player_number: u8) -> bool {
+        self.bookings
+            .iter()
+            .any(|b| {
+                b.team_index as usize == team_index &&
+                    b.player_number == player_number &&
+                    b.player_name == player_name
+            })
+    }
+
+    pub fn add_injury(&mut self, injury: Injury) {
+        self.teams_stats[injury.team_index as usize].add_injury(
+            injury.player_number,
+            injury.injury.clone()
+        );
+        self.injuries.push(injury);
+    }
+
+    pub fn is_injured(&self, team_index: usize, player_name: &str, player_number: u8) -> bool {
+        self.injuries
+            .iter()
+            .any(|i| {
+                i.team_index as usize == team_index &&
+                    i.player_number == player_number &&
+                    i.player_name == player_name
+            })
+    }
+
+    /// Turns the actions played by each team into possession percentages
+    pub fn record_possession(&mut self) {
+        let total = self.teams_stats[0].actions + self.teams_stats[1].actions;
+        if total == 0 {
+            return;
+        }
+
+        for stats in &mut self.teams_stats {
+            stats.possession = ((stats.actions as f32) * 100.0) / (total as f32);
+        }
+    }
+
+    pub fn record_energy(&mut self, teams: &[Team; 2]) {
+        for (team_index, team) in teams.iter().enumerate() {
+            self.energy[team_index] = team.players
+                .iter()
+                .chain(team.bench_players.iter())
+                .chain(team.sent_off_players.iter())
+                .chain(team.injured_players.iter())
+                .map(|p| PlayerEnergy {
+                    player_name: p.name.clone(),
+                    player_number: p.number,
+                    energy: p.energy,
+                })
+                .collect();
+        }
+    }
+
+    pub fn record_players_stats(&mut self, teams: &[Team; 2], final_minute: u8) {
+        for (team_index, team) in teams.iter().enumerate() {
+            let clean_sheet = self.score[1 - team_index] == 0;
+            self.teams_stats[team_index].clean_sheet = clean_sheet;
+
+            self.players_stats[team_index] = team.players
+                .iter()
+                .chain(team.bench_players.iter())
+                .chain(team.sent_off_players.iter())
+                .chain(team.injured_players.iter())
+                .filter(|p| p.match_stats.played)
+                .map(|p| {
+                    let match_stats = PlayerMatchStats {
+                        clean_sheet: p.position == Position::Goalkeeper && !p.match_stats.conceded,
+                        ..p.match_stats.clone()
+                    };
+
+                    PlayerMatchStats {
+                        player_name: p.name.clone(),
+                        player_number: p.number,
+                        minutes_played: p.match_stats.minutes(final_minute),
+                        rating: match_rating(&match_stats),
+                        ..match_stats
+                    }
+                })
+                .collect();
+        }
+    }
+
+    /// Best rated player of the match, ties go to the winning team and then to goals
+    pub fn man_of_the_match(&self) -> Option<ManOfTheMatch> {
+        let winner = self.winner();
+
+        self.players_stats
+            .iter()
+            .enumerate()
+            .flat_map(|(team_index, lines)| lines.iter().map(move |l| (team_index, l)))
+            .max_by(|(team_a, a), (team_b, b)| {
+                a.rating
+                    .total_cmp(&b.rating)
+                    .then((winner == Some(*team_a)).cmp(&(winner == Some(*team_b))))
+                    .then(a.goals.cmp(&b.goals))
+            })
+            .map(|(team_index, line)| ManOfTheMatch {
+                team_index: team_index as u8,
+                player_name: line.player_name.clone(),
+                player_number: line.player_number,
+                rating: line.rating,
+            })
+    }
+}
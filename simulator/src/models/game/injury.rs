@@ -0,0 +1,13 @@
+use serde::{ Deserialize, Serialize };
+use crate::models::game::team_stats::InjuryType;
+
+/// Injury suffered during the match, `days_out` lets the backend set `Status::injured_until`
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Injury {
+    pub team_index: u8,
+    pub player_name: String,
+    pub player_number: u8,
+    pub minute: u8,
+    pub injury: InjuryType,
+    pub days_out: u8,
+}
@@ -0,0 +1,55 @@
+use serde::{ Deserialize, Serialize };
+use crate::models::game::commentary::CommentaryLine;
+use crate::models::game::game_result::{ GameResult, ManOfTheMatch };
+use crate::models::game::match_event::EventRecord;
+use crate::models::game::match_mode::MatchMode;
+use crate::models::game::team::Team;
+
+/// Match minutes played every real minute, 60 plays a match minute every second
+pub const DEFAULT_LIVE_SCALE: u32 = 60;
+
+fn default_scale() -> u32 {
+    DEFAULT_LIVE_SCALE
+}
+
+/// First message of a live match, the same fields as `/game/simulate` and the pace of the match
+#[derive(Deserialize)]
+pub struct LiveRequest {
+    pub teams: Vec<Team>,
+    pub seed: Option<u64>,
+    #[serde(default)]
+    pub mode: MatchMode,
+    // Language of the commentary, no commentary when missing
+    pub lang: Option<String>,
+    #[serde(default = "default_scale")]
+    pub scale: u32,
+}
+
+/// Messages the client can send while the match is being played
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(tag = "control")]
+pub enum LiveControl {
+    // Sends the rest of the match at once
+    FastForward,
+}
+
+/// Messages sent to the client, one `Minute` for every minute with events and a `Summary` at the end
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "frame")]
+pub enum LiveFrame {
+    Minute {
+        minute: u8,
+        added_minute: u8,
+        events: Vec<EventRecord>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        commentary: Vec<CommentaryLine>,
+    },
+    Summary {
+        game_result: Option<Box<GameResult>>,
+        man_of_the_match: Option<ManOfTheMatch>,
+        seed: u64,
+    },
+    Error {
+        message: String,
+    },
+}
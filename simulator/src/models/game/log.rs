@@ -4,7 +4,11 @@ use serde::{Deserialize, Serialize};
 pub struct Log {
     pub player_name: String,
     pub minute: u8,
+    // Minute of stoppage time (45+2 is minute 45 and added_minute 2), 0 in regular time.
+    // The `added_time` log carries the stoppage time announced at the end of the half
+    #[serde(default)]
+    pub added_minute: u8,
     pub description: String,
     pub player_number: u8,
     pub team_name: String
-}
\ No newline at end of file
+}
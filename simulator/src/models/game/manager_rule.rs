@@ -0,0 +1,75 @@
+use serde::{ Deserialize, Serialize };
+use crate::models::game::tactics::{ DefensiveLine, Mentality, Pressing, Tempo, Width };
+use crate::models::player::position::Position;
+
+/// Rule of the manager checked every minute of the match, when all the conditions
+/// of `when` hold the actions of `then` are applied once
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ManagerRule {
+    pub when: RuleCondition,
+    pub then: Vec<RuleAction>,
+    // A rule fires at most once per match
+    #[serde(skip)]
+    pub fired: bool,
+}
+
+/// Conditions of a rule, every field left out always holds
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct RuleCondition {
+    pub from_minute: Option<u8>,
+    pub to_minute: Option<u8>,
+    // Goals scored minus goals conceded, -1 is losing by one
+    pub min_goal_difference: Option<i8>,
+    pub max_goal_difference: Option<i8>,
+    // Players sent off by each team
+    pub min_red_cards: Option<u8>,
+    pub min_opponent_red_cards: Option<u8>,
+    // Injuries suffered by the team
+    pub min_injuries: Option<u8>,
+    // Average energy of the players on the pitch
+    pub max_energy: Option<f32>,
+}
+
+/// Change applied when a rule fires, players are indices in `players`
+/// and bench players indices in `bench_players`, like in substitutions
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub enum RuleAction {
+    Mentality(Mentality),
+    Pressing(Pressing),
+    Tempo(Tempo),
+    Width(Width),
+    DefensiveLine(DefensiveLine),
+    Substitution {
+        player_out: u8,
+        player_in: u8,
+    },
+    Position {
+        player: u8,
+        position: Position,
+    },
+}
+
+/// State of the match from the point of view of the team of the rule
+#[derive(Debug, Clone)]
+pub struct RuleState {
+    pub minute: u8,
+    pub goal_difference: i8,
+    pub red_cards: u8,
+    pub opponent_red_cards: u8,
+    pub injuries: u8,
+    pub energy: f32,
+}
+
+impl RuleCondition {
+    pub fn is_met(&self, state: &RuleState) -> bool {
+        self.from_minute.is_none_or(|m| state.minute >= m) &&
+            self.to_minute.is_none_or(|m| state.minute <= m) &&
+            self.min_goal_difference.is_none_or(|d| state.goal_difference >= d) &&
+            self.max_goal_difference.is_none_or(|d| state.goal_difference <= d) &&
+            self.min_red_cards.is_none_or(|c| state.red_cards >= c) &&
+            self.min_opponent_red_cards.is_none_or(|c| state.opponent_red_cards >= c) &&
+            self.min_injuries.is_none_or(|i| state.injuries >= i) &&
+            self.max_energy.is_none_or(|e| state.energy <= e)
+    }
+}
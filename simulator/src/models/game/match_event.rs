@@ -0,0 +1,395 @@
+use serde::{ Deserialize, Serialize };
+use crate::models::game::booking::BookingType;
+use crate::models::game::log::Log;
+use crate::models::game::shot_zone::ShotZone;
+use crate::models::game::team::Team;
+use crate::models::game::team_stats::InjuryType;
+use crate::models::player::position::Position;
+
+/// Player involved in an event, `index` is their slot in `players` of `team` when it happened
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct EventPlayer {
+    pub team: u8,
+    pub index: u8,
+    pub name: String,
+    pub number: u8,
+}
+
+impl EventPlayer {
+    pub fn new(teams: &[Team; 2], team: usize, index: usize) -> Self {
+        let player = &teams[team].players[index];
+        Self {
+            team: team as u8,
+            index: index as u8,
+            name: player.name.clone(),
+            number: player.number,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum PassOutcome {
+    Completed,
+    Failed,
+    // The receiver was caught offside
+    Offside,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum CrossOutcome {
+    Completed,
+    Failed,
+    // What happened to the ball next, `player` dealt with it
+    HeadedDown,
+    Cleared,
+    Claimed,
+    Intercepted,
+    GoalKick,
+    Corner,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum ShotOutcome {
+    Wide,
+    Caught,
+    Parried,
+    ParriedCorner,
+    Fumbled,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum GoalKind {
+    Shot,
+    Header,
+    Penalty,
+    Corner,
+    FreeKick,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum PenaltyOutcome {
+    Awarded,
+    Rebound,
+    Corner,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum CornerOutcome {
+    Claimed,
+    Rebound,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum FreeKickOutcome {
+    Short,
+    Indirect,
+    Wall,
+    Wide,
+    Saved,
+    Cleared,
+    Delivered,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum SubstitutionReason {
+    Tactical,
+    Injury,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum ShootoutOutcome {
+    Scored,
+    Saved,
+    Missed,
+}
+
+/// Something that happened in the match. Serialized with its name in `type`
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum MatchEvent {
+    Start,
+    KickOff {
+        player: EventPlayer,
+    },
+    HalfTime {
+        player: EventPlayer,
+    },
+    ExtraTime {
+        player: EventPlayer,
+    },
+    AddedTime {
+        minutes: u8,
+    },
+    Pass {
+        passer: EventPlayer,
+        receiver: Option<EventPlayer>,
+        long: bool,
+        outcome: PassOutcome,
+    },
+    Control {
+        player: EventPlayer,
+        success: bool,
+    },
+    Cross {
+        crosser: EventPlayer,
+        player: Option<EventPlayer>,
+        outcome: CrossOutcome,
+    },
+    OffsideTrapBeaten {
+        player: EventPlayer,
+    },
+    Shot {
+        shooter: EventPlayer,
+        goalkeeper: EventPlayer,
+        header: bool,
+        zone: ShotZone,
+        xg: f32,
+        outcome: ShotOutcome,
+    },
+    Goal {
+        scorer: EventPlayer,
+        assistant: Option<EventPlayer>,
+        kind: GoalKind,
+        // Only open play goals have a zone and an xG
+        zone: Option<ShotZone>,
+        xg: Option<f32>,
+    },
+    Penalty {
+        taker: EventPlayer,
+        goalkeeper: EventPlayer,
+        outcome: PenaltyOutcome,
+    },
+    Corner {
+        taker: EventPlayer,
+        goalkeeper: EventPlayer,
+        outcome: CornerOutcome,
+    },
+    FreeKick {
+        taker: EventPlayer,
+        outcome: FreeKickOutcome,
+    },
+    Foul {
+        offender: EventPlayer,
+        victim: EventPlayer,
+    },
+    Card {
+        player: EventPlayer,
+        card: BookingType,
+    },
+    Injury {
+        player: EventPlayer,
+        injury: InjuryType,
+        days_out: u8,
+    },
+    Substitution {
+        player_out: EventPlayer,
+        player_in: EventPlayer,
+        reason: SubstitutionReason,
+    },
+    PositionChange {
+        player: EventPlayer,
+        position: Position,
+    },
+    ManagerRule {
+        team: u8,
+        manager: String,
+    },
+    ShootoutStart,
+    ShootoutKick {
+        taker: EventPlayer,
+        goalkeeper: EventPlayer,
+        outcome: ShootoutOutcome,
+    },
+}
+
+/// Event with its place in the match, `sequence` counts the events from 0
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct EventRecord {
+    pub sequence: u32,
+    pub minute: u8,
+    // Minute of stoppage time, like in `Log`
+    pub added_minute: u8,
+    #[serde(flatten)]
+    pub event: MatchEvent,
+}
+
+impl EventRecord {
+    /// Appends `event` at the end of the match events
+    pub fn push(events: &mut Vec<EventRecord>, minute: u8, event: MatchEvent) {
+        events.push(Self {
+            sequence: events.len() as u32,
+            minute,
+            added_minute: 0,
+            event,
+        });
+    }
+
+    /// Legacy key of the event, like `failed.long_pass`
+    pub fn description(&self) -> String {
+        self.event.description()
+    }
+
+    /// Legacy logs of the event, a goal with an assistant also logs the assist
+    pub fn to_logs(&self, team_names: &[String; 2]) -> Vec<Log> {
+        let log = |player: Option<&EventPlayer>, description: String| {
+            let (player_name, player_number, team_name) = match player {
+                Some(p) => (p.name.clone(), p.number, team_names[p.team as usize].clone()),
+                None => ("game".to_string(), 0, "game".to_string()),
+            };
+
+            Log {
+                player_name,
+                minute: self.minute,
+                added_minute: self.added_minute,
+                description,
+                player_number,
+                team_name,
+            }
+        };
+
+        match &self.event {
+            MatchEvent::Goal { assistant: Some(assistant), scorer, .. } =>
+                vec![
+                    log(Some(scorer), self.description()),
+                    log(Some(assistant), "assist.goal".to_string())
+                ],
+            MatchEvent::ManagerRule { team, manager } =>
+                vec![Log {
+                    player_name: manager.clone(),
+                    team_name: team_names[*team as usize].clone(),
+                    ..log(None, self.description())
+                }],
+            event => vec![log(event.subject(), self.description())],
+        }
+    }
+}
+
+impl MatchEvent {
+    /// Player the legacy log is written for
+    pub fn subject(&self) -> Option<&EventPlayer> {
+        match self {
+            MatchEvent::Start |
+            MatchEvent::AddedTime { .. } |
+            MatchEvent::ManagerRule { .. } |
+            MatchEvent::ShootoutStart => None,
+            MatchEvent::KickOff { player } |
+            MatchEvent::HalfTime { player } |
+            MatchEvent::ExtraTime { player } |
+            MatchEvent::Control { player, .. } |
+            MatchEvent::OffsideTrapBeaten { player } |
+            MatchEvent::Card { player, .. } |
+            MatchEvent::Injury { player, .. } |
+            MatchEvent::PositionChange { player, .. } => Some(player),
+            // Offside is called on the receiver
+            MatchEvent::Pass { passer, receiver, outcome, .. } =>
+                match outcome {
+                    PassOutcome::Offside => receiver.as_ref(),
+                    _ => Some(passer),
+                }
+            MatchEvent::Cross { crosser, player, outcome } =>
+                match outcome {
+                    CrossOutcome::Completed | CrossOutcome::Failed | CrossOutcome::Corner =>
+                        Some(crosser),
+                    _ => player.as_ref(),
+                }
+            MatchEvent::Shot { shooter, .. } => Some(shooter),
+            MatchEvent::Goal { scorer, .. } => Some(scorer),
+            MatchEvent::Penalty { taker, .. } |
+            MatchEvent::Corner { taker, .. } |
+            MatchEvent::FreeKick { taker, .. } |
+            MatchEvent::ShootoutKick { taker, .. } => Some(taker),
+            MatchEvent::Foul { offender, .. } => Some(offender),
+            MatchEvent::Substitution { player_in, .. } => Some(player_in),
+        }
+    }
+
+    /// Legacy key of the event, `outcome.action` like `goal.corner`
+    pub fn description(&self) -> String {
+        let key = match self {
+            MatchEvent::Start => "game.start",
+            MatchEvent::KickOff { .. } => "success.ball",
+            MatchEvent::HalfTime { .. } => "start.second_half",
+            MatchEvent::ExtraTime { .. } => "start.extra_time",
+            MatchEvent::AddedTime { .. } => "added_time",
+            MatchEvent::Pass { long, outcome, .. } =>
+                match (outcome, long) {
+                    (PassOutcome::Completed, false) => "success.pass",
+                    (PassOutcome::Completed, true) => "success.long_pass",
+                    (PassOutcome::Failed, false) => "failed.pass",
+                    (PassOutcome::Failed, true) => "failed.long_pass",
+                    (PassOutcome::Offside, false) => "offside.pass",
+                    (PassOutcome::Offside, true) => "offside.long_pass",
+                }
+            MatchEvent::Control { success: true, .. } => "success.control",
+            MatchEvent::Control { success: false, .. } => "failed.control",
+            MatchEvent::Cross { outcome, .. } =>
+                match outcome {
+                    CrossOutcome::Completed => "success.cross",
+                    CrossOutcome::Failed => "failed.cross",
+                    CrossOutcome::HeadedDown => "header_down.cross",
+                    CrossOutcome::Cleared => "cleared.cross",
+                    CrossOutcome::Claimed => "claimed.cross",
+                    CrossOutcome::Intercepted => "intercepted.cross",
+                    CrossOutcome::GoalKick => "goal_kick.cross",
+                    CrossOutcome::Corner => "corner.cross",
+                }
+            MatchEvent::OffsideTrapBeaten { .. } => "beaten.offside_trap",
+            MatchEvent::Shot { header, outcome, .. } => {
+                let outcome = match outcome {
+                    ShotOutcome::Wide => "failed",
+                    ShotOutcome::Caught => "caught",
+                    ShotOutcome::Parried => "parried",
+                    ShotOutcome::ParriedCorner => "corner",
+                    ShotOutcome::Fumbled => "fumbled",
+                };
+                return format!("{}.{}", outcome, if *header { "header" } else { "shoot" });
+            }
+            MatchEvent::Goal { kind, .. } =>
+                match kind {
+                    GoalKind::Shot => "goal.shoot",
+                    GoalKind::Header => "goal.header",
+                    GoalKind::Penalty => "goal.penalty",
+                    GoalKind::Corner => "goal.corner",
+                    GoalKind::FreeKick => "goal.free_kick",
+                }
+            MatchEvent::Penalty { outcome, .. } =>
+                match outcome {
+                    PenaltyOutcome::Awarded => "awarded.penalty",
+                    PenaltyOutcome::Rebound => "rebound.penalty",
+                    PenaltyOutcome::Corner => "corner.penalty",
+                }
+            MatchEvent::Corner { outcome, .. } =>
+                match outcome {
+                    CornerOutcome::Claimed => "saved.corner",
+                    CornerOutcome::Rebound => "rebound.corner",
+                }
+            MatchEvent::FreeKick { outcome, .. } =>
+                match outcome {
+                    FreeKickOutcome::Short => "short.free_kick",
+                    FreeKickOutcome::Indirect => "indirect.free_kick",
+                    FreeKickOutcome::Wall => "wall.free_kick",
+                    FreeKickOutcome::Wide => "failed.free_kick",
+                    FreeKickOutcome::Saved => "saved.free_kick",
+                    FreeKickOutcome::Cleared => "cleared.free_kick",
+                    FreeKickOutcome::Delivered => "delivered.free_kick",
+                }
+            MatchEvent::Foul { .. } => "foul.dribble",
+            MatchEvent::Card { card, .. } => card.log_description(),
+            MatchEvent::Injury { injury: InjuryType::Soft, .. } => "soft.injury",
+            MatchEvent::Injury { injury: InjuryType::Hard, .. } => "hard.injury",
+            MatchEvent::Substitution { reason: SubstitutionReason::Tactical, .. } => "substitution",
+            MatchEvent::Substitution { reason: SubstitutionReason::Injury, .. } => "injury.substitution",
+            MatchEvent::PositionChange { .. } => "change.position",
+            MatchEvent::ManagerRule { .. } => "fired.manager_rule",
+            MatchEvent::ShootoutStart => "start.shootout",
+            MatchEvent::ShootoutKick { outcome, .. } =>
+                match outcome {
+                    ShootoutOutcome::Scored => "scored.shootout",
+                    ShootoutOutcome::Saved => "saved.shootout",
+                    ShootoutOutcome::Missed => "missed.shootout",
+                }
+        };
+
+        key.to_string()
+    }
+}
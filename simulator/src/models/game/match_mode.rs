@@ -0,0 +1,33 @@
+use serde::{ Deserialize, Serialize };
+
+/// League games can end in a draw, knockout games go to extra time and penalties
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchMode {
+    #[default]
+    League,
+    Knockout,
+}
+
+impl MatchMode {
+    /// Last minute of the match before stoppage time, extra time included
+    pub fn last_minute(&self) -> u8 {
+        match self {
+            MatchMode::League => 90,
+            MatchMode::Knockout => 120,
+        }
+    }
+}
+
+/// How the match was decided
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+pub enum MatchOutcome {
+    // After 90 minutes, league draws included
+    #[default]
+    RegularTime,
+    ExtraTime,
+    // Knockout draw decided by a penalty shootout, `score` holds the kicks scored
+    Penalties {
+        score: [u8; 2],
+    },
+}
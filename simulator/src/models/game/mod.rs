@@ -3,4 +3,19 @@ pub mod game_result;
 pub mod team_stats;
 pub mod log;
 pub mod team;
-pub mod aura;
\ No newline at end of file
+pub mod aura;
+pub mod batch_result;
+pub mod substitution;
+pub mod booking;
+pub mod free_kick;
+pub mod set_piece;
+pub mod injury;
+pub mod offside;
+pub mod match_mode;
+pub mod tactics;
+pub mod manager_rule;
+pub mod shot_zone;
+pub mod save_type;
+pub mod match_event;
+pub mod commentary;
+pub mod live;
@@ -0,0 +1,8 @@
+/// Call of the assistant referee on a pass to a forward run
+#[derive(Debug, Clone, PartialEq)]
+pub enum OffsideCall {
+    Onside,
+    Offside,
+    // Onside and past the offside trap, through on goal
+    TrapBeaten,
+}
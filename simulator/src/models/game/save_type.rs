@@ -0,0 +1,14 @@
+use serde::{ Deserialize, Serialize };
+
+/// How the goalkeeper stops a shot on target
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum SaveType {
+    // Held, the goalkeeper keeps the ball
+    Catch,
+    // Pushed away, the ball stays in play in the box
+    ParryInPlay,
+    // Pushed away over the line for a corner
+    ParryCorner,
+    // Spilled, an attacker gets a second chance from close range
+    Fumble,
+}
@@ -0,0 +1,35 @@
+use serde::{ Deserialize, Serialize };
+use crate::models::player::player::Player;
+
+/// Dead ball situations with a designated taker
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub enum SetPiece {
+    Penalty,
+    Corner,
+    FreeKick,
+}
+
+impl SetPiece {
+    /// Taker priority from the player instructions, 1 is the first choice and 0 means not designated
+    pub fn priority(&self, player: &Player) -> u8 {
+        match self {
+            SetPiece::Penalty => player.instructions.penalty_kicker,
+            SetPiece::Corner => player.instructions.corner_kicker,
+            SetPiece::FreeKick => player.instructions.free_kick_kicker,
+        }
+    }
+
+    /// Ability of the player to take this set piece, used to break ties
+    /// and to pick a taker when nobody on the pitch is designated
+    pub fn skill(&self, player: &Player) -> u16 {
+        let kicking = player.skills.kicking as u16;
+        let crossing = player.skills.crossing as u16;
+        let composure = player.skills.composure as u16;
+
+        match self {
+            SetPiece::Penalty => kicking + composure * 2,
+            SetPiece::Corner => kicking + crossing * 2 + composure,
+            SetPiece::FreeKick => kicking * 2 + crossing + composure,
+        }
+    }
+}
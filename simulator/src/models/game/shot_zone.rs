@@ -0,0 +1,11 @@
+use serde::{ Deserialize, Serialize };
+
+/// Where a shot is taken from, the closer to goal the higher its xG
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum ShotZone {
+    LongRange,
+    EdgeOfBox,
+    InsideBox,
+    // Alone against the goalkeeper, only after running past the defensive line
+    OneOnOne,
+}
@@ -0,0 +1,19 @@
+use serde::{ Deserialize, Serialize };
+
+/// Maximum substitutions a team can make during a match
+pub const MAX_SUBSTITUTIONS: usize = 5;
+
+/// Maximum stoppages a team can use to make substitutions, half time doesn't count
+pub const MAX_SUBSTITUTION_WINDOWS: usize = 3;
+
+/// Minute when half time substitutions are made
+pub const HALF_TIME_MINUTE: u8 = 45;
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Substitution {
+    pub minute: u8,
+    // Index of the player leaving in `players`
+    pub player_out: u8,
+    // Index of the player entering in `bench_players`
+    pub player_in: u8,
+}
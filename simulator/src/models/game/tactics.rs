@@ -0,0 +1,72 @@
+use serde::{ Deserialize, Serialize };
+
+/// How much the team commits to attack
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+pub enum Mentality {
+    UltraDefensive,
+    Defensive,
+    #[default]
+    Balanced,
+    Attacking,
+    AllOutAttack,
+}
+
+/// How hard the team chases the ball when it doesn't have it
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+pub enum Pressing {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+/// How fast the team moves the ball
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+pub enum Tempo {
+    Slow,
+    #[default]
+    Normal,
+    Fast,
+}
+
+/// How much the team uses the flanks
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+pub enum Width {
+    Narrow,
+    #[default]
+    Normal,
+    Wide,
+}
+
+/// Where the defenders hold their line
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+pub enum DefensiveLine {
+    Deep,
+    #[default]
+    Normal,
+    High,
+}
+
+/// Team instructions chosen by the manager, every field is optional in the request
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct Tactics {
+    pub mentality: Mentality,
+    pub pressing: Pressing,
+    pub tempo: Tempo,
+    pub width: Width,
+    pub defensive_line: DefensiveLine,
+}
+
+impl Mentality {
+    /// From -2 (ultra defensive) to 2 (all out attack)
+    pub fn attacking_level(&self) -> i32 {
+        match self {
+            Mentality::UltraDefensive => -2,
+            Mentality::Defensive => -1,
+            Mentality::Balanced => 0,
+            Mentality::Attacking => 1,
+            Mentality::AllOutAttack => 2,
+        }
+    }
+}
@@ -1,6 +1,23 @@
 use serde::{ Deserialize, Serialize };
 use crate::models::player::player::Player;
 use crate::models::game::aura::Aura;
+use crate::models::game::set_piece::SetPiece;
+use crate::models::game::tactics::Tactics;
+use crate::models::game::manager_rule::{ ManagerRule, RuleAction };
+use crate::logics::game::tactics::pressing_drain_factor;
+use crate::models::game::substitution::{
+    Substitution,
+    MAX_SUBSTITUTIONS,
+    MAX_SUBSTITUTION_WINDOWS,
+};
+use crate::models::player::position::Position;
+use crate::logics::player::fatigue::{
+    ENDLESS_ENGINE_FACTOR,
+    GOALKEEPER_MINUTE_FACTOR,
+    MINUTE_DRAIN,
+    PRESSING_DRAIN,
+};
+use std::cmp::Reverse;
 use std::collections::HashMap;
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -10,6 +27,24 @@ pub struct Team {
     pub players: Vec<Player>,
     pub bench_players: Vec<Player>,
     pub aura: [String; 3],
+    #[serde(default)]
+    pub tactics: Tactics,
+    #[serde(default)]
+    pub substitutions: Vec<Substitution>,
+    #[serde(default)]
+    pub manager_rules: Vec<ManagerRule>,
+    // Substitutions already applied during the match
+    #[serde(skip)]
+    pub substitutions_made: Vec<Substitution>,
+    // Minutes of the stoppages used to make substitutions, half time doesn't use one
+    #[serde(skip)]
+    pub substitution_windows: Vec<u8>,
+    // Players removed from the pitch by a red card
+    #[serde(skip)]
+    pub sent_off_players: Vec<Player>,
+    // Players forced off by a hard injury when no substitutions were left
+    #[serde(skip)]
+    pub injured_players: Vec<Player>,
 }
 
 impl Team {
@@ -65,6 +100,196 @@ impl Team {
         }
     }
 
+    pub fn energy_drain_factor(&self) -> f32 {
+        if self.aura.iter().any(|a| a == "EndlessEngine") {
+            ENDLESS_ENGINE_FACTOR
+        } else {
+            1.0
+        }
+    }
+
+    pub fn drain_player(&mut self, player_index: usize, amount: f32) {
+        let drain_factor = self.energy_drain_factor();
+        self.players[player_index].drain_energy(amount, drain_factor);
+    }
+
+    /// Energy lost by every player on the pitch during one minute,
+    /// the team without the ball also pays for pressing, more the higher it presses
+    pub fn drain_minute(&mut self, pressing: bool) {
+        let drain_factor = self.energy_drain_factor();
+        let amount = if pressing {
+            MINUTE_DRAIN + PRESSING_DRAIN * pressing_drain_factor(&self.tactics.pressing)
+        } else {
+            MINUTE_DRAIN
+        };
+
+        for player in &mut self.players {
+            let position_factor = if player.position == Position::Goalkeeper {
+                GOALKEEPER_MINUTE_FACTOR
+            } else {
+                1.0
+            };
+            player.drain_energy(amount * position_factor, drain_factor);
+        }
+    }
+
+    /// `half_time` is the break between both halves, not the stoppage time of the first half
+    pub fn can_substitute(&self, minute: u8, half_time: bool) -> bool {
+        if self.substitutions_made.len() >= MAX_SUBSTITUTIONS {
+            return false;
+        }
+
+        // Half time and a stoppage already opened don't use a new window
+        half_time ||
+            self.substitution_windows.contains(&minute) ||
+            self.substitution_windows.len() < MAX_SUBSTITUTION_WINDOWS
+    }
+
+    pub fn is_bench_player_available(&self, player_in: u8) -> bool {
+        (player_in as usize) < self.bench_players.len() &&
+            !self.substitutions_made.iter().any(|s| s.player_in == player_in)
+    }
+
+    /// The substitute takes the slot, role and current position of the outgoing player,
+    /// the outgoing player stays on the bench and can't come back.
+    pub fn substitute(&mut self, player_out: u8, player_in: u8, minute: u8, half_time: bool) {
+        let out_index = player_out as usize;
+        let in_index = player_in as usize;
+
+        let position = self.players[out_index].position.clone();
+        let current_position = self.players[out_index].current_position.clone();
+
+        std::mem::swap(&mut self.players[out_index], &mut self.bench_players[in_index]);
+
+        self.players[out_index].position = position;
+        self.players[out_index].current_position = current_position;
+
+        self.players[out_index].match_stats.enter(minute);
+        self.bench_players[in_index].match_stats.leave(minute);
+
+        self.substitutions_made.push(Substitution { minute, player_out, player_in });
+        if !half_time && !self.substitution_windows.contains(&minute) {
+            self.substitution_windows.push(minute);
+        }
+    }
+
+    /// Removes the player from the pitch for the rest of the match.
+    /// Scheduled substitutions and manager rules are shifted to the new slots and,
+    /// if the goalkeeper leaves, the outfield player with the best hands takes the gloves.
+    fn remove_player(&mut self, player_index: usize) -> Player {
+        let player = self.players.remove(player_index);
+        let removed = player_index as u8;
+
+        self.substitutions.retain(|s| s.player_out != removed);
+        for sub in &mut self.substitutions {
+            if sub.player_out > removed {
+                sub.player_out -= 1;
+            }
+        }
+
+        for rule in &mut self.manager_rules {
+            rule.then.retain(|action| {
+                match action {
+                    RuleAction::Substitution { player_out, .. } => *player_out != removed,
+                    RuleAction::Position { player, .. } => *player != removed,
+                    _ => true,
+                }
+            });
+            for action in &mut rule.then {
+                match action {
+                    RuleAction::Substitution { player_out: slot, .. } | RuleAction::Position { player: slot, .. } if *slot > removed => {
+                        *slot -= 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if player.position == Position::Goalkeeper {
+            let replacement = self.players
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, p)| (p.skills.handling as u16) + (p.skills.reflexes as u16))
+                .map(|(i, _)| i);
+
+            if let Some(i) = replacement {
+                self.players[i].position = Position::Goalkeeper;
+                self.players[i].current_position = Position::Goalkeeper;
+            }
+        }
+
+        player
+    }
+
+    pub fn send_off(&mut self, player_index: usize) {
+        let player = self.remove_player(player_index);
+        self.sent_off_players.push(player);
+    }
+
+    /// A hard injured player that can't be replaced leaves the team a player short
+    pub fn withdraw_injured(&mut self, player_index: usize) {
+        let player = self.remove_player(player_index);
+        self.injured_players.push(player);
+    }
+
+    /// Replaces a hard injured player with a bench player, preferring one of the same
+    /// position that isn't planned for a later substitution. The scheduled substitutions
+    /// of the injured player are dropped. Returns the bench index used, if any.
+    pub fn injury_substitution(&mut self, player_index: usize, minute: u8) -> Option<u8> {
+        if !self.can_substitute(minute, false) {
+            return None;
+        }
+
+        let position = self.players[player_index].position.clone();
+        let player_in = (0..self.bench_players.len() as u8)
+            .filter(|i| self.is_bench_player_available(*i))
+            .max_by_key(|i| {
+                let same_position = self.bench_players[*i as usize].position == position;
+                let unplanned = !self.substitutions.iter().any(|s| s.player_in == *i);
+                (same_position, unplanned, Reverse(*i))
+            })?;
+
+        let slot = player_index as u8;
+        self.substitutions.retain(|s| s.player_out != slot);
+        self.substitute(slot, player_in, minute, false);
+
+        Some(player_in)
+    }
+
+    /// Taker of `set_piece`: the player on the pitch with the best (lowest positive)
+    /// priority, so a substituted or sent off taker falls back to the next one.
+    /// Ties and teams without designation pick the most skilled player.
+    pub fn set_piece_taker(&self, set_piece: SetPiece) -> usize {
+        let best_priority = self.players
+            .iter()
+            .map(|p| set_piece.priority(p))
+            .filter(|k| *k > 0)
+            .min();
+
+        self.players
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| best_priority.is_none_or(|best| set_piece.priority(p) == best))
+            .max_by_key(|(_, p)| set_piece.skill(p))
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    }
+
+    /// Order of the penalty shootout, designated penalty takers by priority and then
+    /// the rest of the players on the pitch by their penalty skill
+    pub fn shootout_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.players.len()).collect();
+        order.sort_by_key(|&i| {
+            let player = &self.players[i];
+            let priority = match SetPiece::Penalty.priority(player) {
+                0 => u8::MAX,
+                priority => priority,
+            };
+            (priority, Reverse(SetPiece::Penalty.skill(player)), i)
+        });
+        order
+    }
+
     pub fn total_height(&self) -> u32 {
         self.players
             .iter()
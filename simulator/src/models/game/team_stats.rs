@@ -1,13 +1,47 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub enum InjuryType {
     Soft,
     Hard,
 }
 
+/// Match statistics of a team, players are referenced by their shirt number
 #[derive(Deserialize, Serialize, Debug, Clone, Default)]
 pub struct TeamStats {
+    // Share of the actions played with the ball (0 - 100)
+    pub possession: f32,
+    #[serde(skip)]
+    pub actions: u32,
+
+    pub passes: i32,
+    pub passes_suc: i32,
+    pub shoots: i32,
+    pub shoots_suc: i32,
+    // Expected goals of the shots and headers, set pieces excluded
+    pub xg: f32,
+    pub dribbles: i32,
+    pub dribbles_suc: i32,
+    pub long_pass: i32,
+    pub long_pass_suc: i32,
+    pub cross: i32,
+    pub cross_suc: i32,
+    pub penalties: i32,
+    pub penalties_goals: i32,
+    pub corners: i32,
+    pub corners_goals: i32,
+    pub controls: i32,
+    pub controls_suc: i32,
+    pub free_kicks: i32,
+    pub free_kicks_goals: i32,
+    pub fouls: i32,
+    pub offsides: i32,
+    // Shots stopped and crosses taken by the goalkeepers
+    pub saves: i32,
+    pub claims: i32,
+    // No goals conceded, shootout penalties excluded
+    pub clean_sheet: bool,
+
     pub red_cards: Vec<u8>,
     pub yellow_cards: Vec<u8>,
     pub scorers: Vec<u8>,
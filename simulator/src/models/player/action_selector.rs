@@ -1,10 +1,23 @@
 use crate::models::player::position::Position;
-use crate::utils::generate_random_number::generate_number_by_range;
+use crate::utils::generate_random_number::{ GameRng, generate_seeded_number_by_range };
 use crate::models::player::actions::Actions;
 use crate::models::game::game_result::GameResult;
 use crate::models::game::team::Team;
 use crate::models::player::instructions::OffensiveInstruction;
-use crate::models::game::log::Log;
+use crate::models::game::match_event::EventRecord;
+use crate::logics::player::fatigue::ACTION_DRAIN;
+use crate::models::game::tactics::Tactics;
+use crate::models::game::shot_zone::ShotZone;
+use crate::logics::game::tactics::{
+    shift_weight,
+    tempo_level,
+    width_level,
+    MENTALITY_DRIBBLE_SHIFT,
+    MENTALITY_PASS_SHIFT,
+    MENTALITY_SHOOT_SHIFT,
+    TEMPO_LONG_PASS_SHIFT,
+    WIDTH_CROSS_SHIFT,
+};
 
 pub struct ActionSelector;
 
@@ -62,6 +75,60 @@ impl Position {
             Position::Striker => tuple_to_probs(crate::logics::player::actions::striker::ACTIONS),
         }
     }
+
+    /// Receiver preferences of a short pass played from this position
+    pub fn passes(&self) -> &'static [(Position, u8)] {
+        match self {
+            Position::Goalkeeper => crate::logics::player::actions::goalkeeper::PASSES,
+            Position::Defender => crate::logics::player::actions::defender::PASSES,
+            Position::Left_Back => crate::logics::player::actions::left_back::PASSES,
+            Position::Right_Back => crate::logics::player::actions::right_back::PASSES,
+            Position::Defensive_Midfield => crate::logics::player::actions::defensive_midfield::PASSES,
+            Position::Midfielder => crate::logics::player::actions::midfielder::PASSES,
+            Position::Left_Midfield => crate::logics::player::actions::left_midfield::PASSES,
+            Position::Right_Midfield => crate::logics::player::actions::right_midfield::PASSES,
+            Position::Attacking_Midfield => crate::logics::player::actions::attacking_midfield::PASSES,
+            Position::Left_Wing => crate::logics::player::actions::left_wing::PASSES,
+            Position::Right_Wing => crate::logics::player::actions::right_wing::PASSES,
+            Position::Striker => crate::logics::player::actions::striker::PASSES,
+        }
+    }
+
+    /// Zones the shots of this position are taken from
+    pub fn shot_zones(&self) -> &'static [(ShotZone, u8)] {
+        match self {
+            Position::Goalkeeper => crate::logics::player::actions::goalkeeper::SHOT_ZONES,
+            Position::Defender => crate::logics::player::actions::defender::SHOT_ZONES,
+            Position::Left_Back => crate::logics::player::actions::left_back::SHOT_ZONES,
+            Position::Right_Back => crate::logics::player::actions::right_back::SHOT_ZONES,
+            Position::Defensive_Midfield => crate::logics::player::actions::defensive_midfield::SHOT_ZONES,
+            Position::Midfielder => crate::logics::player::actions::midfielder::SHOT_ZONES,
+            Position::Left_Midfield => crate::logics::player::actions::left_midfield::SHOT_ZONES,
+            Position::Right_Midfield => crate::logics::player::actions::right_midfield::SHOT_ZONES,
+            Position::Attacking_Midfield => crate::logics::player::actions::attacking_midfield::SHOT_ZONES,
+            Position::Left_Wing => crate::logics::player::actions::left_wing::SHOT_ZONES,
+            Position::Right_Wing => crate::logics::player::actions::right_wing::SHOT_ZONES,
+            Position::Striker => crate::logics::player::actions::striker::SHOT_ZONES,
+        }
+    }
+
+    /// Receiver preferences of a long pass played from this position
+    pub fn long_passes(&self) -> &'static [(Position, u8)] {
+        match self {
+            Position::Goalkeeper => crate::logics::player::actions::goalkeeper::LONG_PASSES,
+            Position::Defender => crate::logics::player::actions::defender::LONG_PASSES,
+            Position::Left_Back => crate::logics::player::actions::left_back::LONG_PASSES,
+            Position::Right_Back => crate::logics::player::actions::right_back::LONG_PASSES,
+            Position::Defensive_Midfield => crate::logics::player::actions::defensive_midfield::LONG_PASSES,
+            Position::Midfielder => crate::logics::player::actions::midfielder::LONG_PASSES,
+            Position::Left_Midfield => crate::logics::player::actions::left_midfield::LONG_PASSES,
+            Position::Right_Midfield => crate::logics::player::actions::right_midfield::LONG_PASSES,
+            Position::Attacking_Midfield => crate::logics::player::actions::attacking_midfield::LONG_PASSES,
+            Position::Left_Wing => crate::logics::player::actions::left_wing::LONG_PASSES,
+            Position::Right_Wing => crate::logics::player::actions::right_wing::LONG_PASSES,
+            Position::Striker => crate::logics::player::actions::striker::LONG_PASSES,
+        }
+    }
 }
 
 impl ActionSelector {
@@ -70,8 +137,9 @@ impl ActionSelector {
         ball_possession: &mut [u8; 2],
         last_pass_player: &mut [u8; 2],
         minutes: u8,
-        logs: &mut Vec<Log>,
+        events: &mut Vec<EventRecord>,
         game_result: &mut GameResult,
+        rng: &mut GameRng,
     ) {
         let team_idx = ball_possession[0] as usize;
         let player_idx = ball_possession[1] as usize;
@@ -81,6 +149,7 @@ impl ActionSelector {
 
         adjust_probabilities_if_alone(&mut probs, teams, team_idx as u8, player_idx as u8);
         adjust_probabilities_by_player_instructions(&mut probs, &player.instructions);
+        adjust_probabilities_by_tactics(&mut probs, &teams[team_idx].tactics);
 
         let weights = [
             ("shoot", probs.shoot),
@@ -91,20 +160,30 @@ impl ActionSelector {
             ("cross", probs.cross),
         ];
 
+        // Taking an action tires the ball holder
+        teams[team_idx].drain_player(player_idx, ACTION_DRAIN);
+        game_result.teams_stats[team_idx].actions += 1;
+
+        // Goalkeepers only distribute the ball
+        if teams[team_idx].players[player_idx].current_position == Position::Goalkeeper {
+            Actions::distribute(teams, ball_possession, last_pass_player, events, minutes, game_result, rng);
+            return;
+        }
+
         let total: u32 = weights.iter().map(|(_, w)| *w).sum();
-        let roll = generate_number_by_range(0, (total - 1) as u8);
+        let roll = generate_seeded_number_by_range(rng, 0, (total - 1) as u8);
 
         let mut sum = 0;
         for (action, weight) in weights {
             sum += weight;
             if roll < sum as u8 {
                 match action {
-                    "shoot" => Actions::shoot(teams, ball_possession, last_pass_player, logs, minutes, game_result),
-                    "pass" => Actions::pass(teams, ball_possession, last_pass_player, logs, minutes),
-                    "dribble" => Actions::dribble(teams, ball_possession, logs, minutes),
+                    "shoot" => Actions::shoot(teams, ball_possession, last_pass_player, events, minutes, game_result, rng),
+                    "pass" => Actions::pass(teams, ball_possession, last_pass_player, events, minutes, game_result, rng),
+                    "dribble" => Actions::dribble(teams, ball_possession, last_pass_player, events, minutes, game_result, rng),
                     "advance" => Actions::advance(teams, ball_possession),
-                    "long_pass" => Actions::long_pass(teams, ball_possession, logs, minutes, last_pass_player),
-                    "cross" => Actions::cross(teams, ball_possession, logs, minutes),
+                    "long_pass" => Actions::long_pass(teams, ball_possession, events, minutes, last_pass_player, game_result, rng),
+                    "cross" => Actions::cross(teams, ball_possession, last_pass_player, events, minutes, game_result, rng),
                     _ => unreachable!(),
                 };
                 return;
@@ -160,3 +239,19 @@ fn adjust_probabilities_by_player_instructions(
     }
 }
 
+/// Team mentality, tempo and width move weight between passing and the riskier actions
+fn adjust_probabilities_by_tactics(probs: &mut ActionProbabilities, tactics: &Tactics) {
+    let mentality = tactics.mentality.attacking_level();
+    let tempo = tempo_level(&tactics.tempo);
+    let width = width_level(&tactics.width);
+
+    probs.shoot = shift_weight(probs.shoot, mentality * MENTALITY_SHOOT_SHIFT);
+    probs.dribble = shift_weight(probs.dribble, mentality * MENTALITY_DRIBBLE_SHIFT);
+    probs.long_pass = shift_weight(probs.long_pass, tempo * TEMPO_LONG_PASS_SHIFT);
+    probs.cross = shift_weight(probs.cross, width * WIDTH_CROSS_SHIFT);
+    probs.pass = shift_weight(
+        probs.pass,
+        mentality * MENTALITY_PASS_SHIFT - tempo * TEMPO_LONG_PASS_SHIFT - width * WIDTH_CROSS_SHIFT
+    );
+}
+
@@ -2,21 +2,84 @@ use crate::models::game::team::Team;
 use crate::models::player::player::Player;
 use crate::models::player::position::Position;
 use crate::models::game::game_result::GameResult;
-use crate::models::game::log::Log;
-use crate::utils::generate_random_number::generate_number_by_range;
+use crate::models::game::match_event::{
+    CornerOutcome,
+    CrossOutcome,
+    EventPlayer,
+    EventRecord,
+    FreeKickOutcome,
+    GoalKind,
+    MatchEvent,
+    PassOutcome,
+    PenaltyOutcome,
+    ShotOutcome,
+    SubstitutionReason,
+};
+use crate::models::game::booking::{ Booking, BookingType };
+use crate::models::game::injury::Injury;
+use crate::models::game::team_stats::InjuryType;
+use crate::models::game::free_kick::FreeKickZone;
+use crate::models::game::set_piece::SetPiece;
+use crate::models::game::offside::OffsideCall;
+use crate::models::game::shot_zone::ShotZone;
+use crate::models::game::save_type::SaveType;
+use crate::utils::generate_random_number::{ GameRng, generate_seeded_number_by_range };
+use crate::logics::player::fatigue::{ DUEL_DRAIN, ACTION_DRAIN, energy_performance_factor };
+use crate::logics::player::fouls::{
+    foul_chance,
+    penalty_chance,
+    yellow_chance,
+    reckless_chance,
+    MIN_PLAYERS_ON_PITCH,
+};
+use crate::logics::player::tackling::tackle_bonus;
+use crate::logics::game::tactics::{ pressing_foul_bonus, pressing_tackle_bonus };
+use crate::logics::player::offside::{
+    can_be_offside,
+    is_defensive_line,
+    offside_chance,
+    trap_beaten_chance,
+};
+use crate::logics::player::passing::{ pass_weight, is_marked, receiver_weight };
+use crate::logics::player::shooting::{ goal_chance, shot_quality, shot_xg, REFERENCE_KEEPER };
+use crate::logics::player::aerial::{
+    aerial_score,
+    aerial_win_chance,
+    header_scoring_chance,
+    is_box_position,
+    box_distance,
+    CLEARED_CORNER_CHANCE,
+    HEADER_SHOT_CHANCE,
+    INTERCEPTED_CROSS_CHANCE,
+    WIDE_CROSS_CHANCE,
+};
+use crate::logics::player::goalkeeping::{
+    claim_chance,
+    long_kick_chance,
+    save_type,
+    ON_TARGET_CHANCE,
+};
+use crate::logics::player::injuries::{
+    injury_chance,
+    HARD_INJURY_CHANCE,
+    HARD_INJURY_DAYS,
+    SOFT_INJURY_DAYS,
+};
 use rand::prelude::*;
-use rand_distr::{ Normal, Distribution };
 
 #[derive(Debug, Clone)]
 pub struct Actions;
 
-//? Missing create the stats system to register the actions of each player during the match!!!
-
 impl Actions {
     fn stamina_factor(player: &Player) -> f32 {
         ((player.skills.stamina as f32) / 100.0).clamp(0.7, 1.0)
     }
 
+    /// Stamina level scaled by the energy the player has left in the match
+    fn fitness_factor(player: &Player) -> f32 {
+        Self::stamina_factor(player) * energy_performance_factor(player.energy)
+    }
+
     /// Get mutable reference to player with ball
     fn get_player<'a>(teams: &'a mut [Team; 2], ball_possession: &mut [u8; 2]) -> &'a mut Player {
         // Access indices safely and convert u8 to usize
@@ -27,14 +90,19 @@ impl Actions {
         teams: &mut [Team; 2],
         ball_possession: &mut [u8; 2],
         last_pass_player: &mut [u8; 2],
-        logs: &mut Vec<Log>,
-        minute: u8
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        game_result: &mut GameResult,
+        rng: &mut GameRng
     ) -> bool {
         // Current ball holder
         let passer =
             teams[ball_possession[0] as usize].players[ball_possession[1] as usize].clone();
 
         let passer_team = ball_possession[0] as usize;
+        let passer_index = ball_possession[1];
+        game_result.teams_stats[passer_team].passes += 1;
+        teams[passer_team].players[passer_index as usize].match_stats.passes += 1;
 
         // Calculate pass quality
         let pass_quality =
@@ -43,20 +111,39 @@ impl Actions {
             (passer.skills.composure as f32) * 0.15;
 
         let base_chance = (pass_quality / 100.0).clamp(0.05, 0.95);
-        let success_chance = base_chance * Self::stamina_factor(&passer.clone());
+        let success_chance = base_chance * Self::fitness_factor(&passer.clone());
 
-        let roll = (generate_number_by_range(0, 100) as f32) / 100.0;
+        let roll = (generate_seeded_number_by_range(rng, 0, 100) as f32) / 100.0;
 
         if roll <= success_chance {
             // Successful pass → choose new teammate
-            let (team_id, player_id) = Self::select_pass_target(teams, ball_possession);
+            let (team_id, player_id) = Self::select_pass_target(teams, ball_possession, false, rng);
+
+            // Through balls to the forwards can be flagged offside
+            let call = Self::offside_call(teams, team_id as usize, player_id as usize, true, rng);
+            if call == OffsideCall::Offside {
+                Self::flag_offside(
+                    teams,
+                    ball_possession,
+                    last_pass_player,
+                    player_id as usize,
+                    false,
+                    events,
+                    minute,
+                    game_result,
+                    rng
+                );
+                return true;
+            }
 
-            logs.push(Log {
-                player_name: passer.name.clone(),
-                player_number: passer.number,
-                minute,
-                team_name: teams[passer_team].name.clone(),
-                description: "success.pass".to_string(),
+            game_result.teams_stats[passer_team].passes_suc += 1;
+            teams[passer_team].players[passer_index as usize].match_stats.passes_suc += 1;
+
+            EventRecord::push(events, minute, MatchEvent::Pass {
+                passer: EventPlayer::new(teams, passer_team, passer_index as usize),
+                receiver: Some(EventPlayer::new(teams, team_id as usize, player_id as usize)),
+                long: false,
+                outcome: PassOutcome::Completed,
             });
 
             // Update possession tentatively
@@ -64,26 +151,29 @@ impl Actions {
             ball_possession[1] = player_id;
 
             // Attempt to control the ball
-            let control_success = Self::control(teams, ball_possession, logs, minute);
+            let control_success = Self::control(teams, ball_possession, last_pass_player, events, minute, game_result, rng);
 
             if control_success {
                 // Control successful → update last passer
-                last_pass_player[0] = team_id;
-                last_pass_player[1] = player_id;
+                last_pass_player[0] = passer_team as u8;
+                last_pass_player[1] = passer_index;
+
+                if call == OffsideCall::TrapBeaten {
+                    Self::beat_offside_trap(teams, ball_possession, last_pass_player, events, minute, game_result, rng);
+                }
             } else {
                 // Receiver failed to control → trigger rebound
-                Self::rebound(teams, ball_possession, last_pass_player);
+                Self::rebound(teams, ball_possession, last_pass_player, rng);
             }
         } else {
             // Pass failed → trigger rebound
-            logs.push(Log {
-                player_name: passer.name.clone(),
-                player_number: passer.number,
-                minute,
-                team_name: teams[passer_team].name.clone(),
-                description: "failed.pass".to_string(),
+            EventRecord::push(events, minute, MatchEvent::Pass {
+                passer: EventPlayer::new(teams, passer_team, passer_index as usize),
+                receiver: None,
+                long: false,
+                outcome: PassOutcome::Failed,
             });
-            Self::rebound(teams, ball_possession, last_pass_player);
+            Self::rebound(teams, ball_possession, last_pass_player, rng);
         }
 
         true // action completed
@@ -92,8 +182,11 @@ impl Actions {
     pub fn dribble(
         teams: &mut [Team; 2],
         ball_possession: &mut [u8; 2],
-        logs: &mut Vec<Log>,
-        minute: u8
+        last_pass_player: &mut [u8; 2],
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        game_result: &mut GameResult,
+        rng: &mut GameRng
     ) -> bool {
         let team_id = ball_possession[0] as usize;
         let player_id = ball_possession[1] as usize;
@@ -115,7 +208,8 @@ impl Actions {
         let defender_index = match defender_index_opt {
             Some(i) => i,
             None =>
-                generate_number_by_range(
+                generate_seeded_number_by_range(
+                    rng,
                     0,
                     (teams[opponent_team].players.len() as u8) - 1
                 ) as usize,
@@ -125,87 +219,493 @@ impl Actions {
 
         // --- DRIBBLE VS DEFENSE ---
         let attacker_score = (attacker.skills.dribbling as i32) + (attacker.skills.speed as i32);
-        let defender_score =
-            (defender.skills.defense as i32) +
-            (defender.skills.physical as i32) +
-            (defender.skills.aggression as i32);
+        let defender_score = (
+            (((defender.skills.defense as i32) +
+                (defender.skills.physical as i32) +
+                (defender.skills.aggression as i32)) as f32) *
+            energy_performance_factor(defender.energy)
+        ).round() as i32 +
+            tackle_bonus(&defender.instructions.defensive_style()) +
+            pressing_tackle_bonus(&teams[opponent_team].tactics.pressing);
 
         let difficulty = defender_score - attacker_score;
 
         let base_dribble_chance = (65 - difficulty).clamp(10, 95) * 3;
         let dribble_chance = (
-            (base_dribble_chance as f32) * Self::stamina_factor(attacker)
+            (base_dribble_chance as f32) * Self::fitness_factor(attacker)
         ).round() as u8;
 
-        let roll = generate_number_by_range(0, 100);
+        let roll = generate_seeded_number_by_range(rng, 0, 100);
 
         let success = roll < (dribble_chance as u8);
 
+        game_result.teams_stats[team_id].dribbles += 1;
+        if success {
+            game_result.teams_stats[team_id].dribbles_suc += 1;
+            teams[team_id].players[player_id].match_stats.dribbles_won += 1;
+        } else {
+            teams[team_id].players[player_id].match_stats.dribbles_lost += 1;
+            teams[opponent_team].players[defender_index].match_stats.tackles += 1;
+        }
+
+        // --- THE DUEL TIRES BOTH PLAYERS ---
+        teams[team_id].drain_player(player_id, DUEL_DRAIN);
+        teams[opponent_team].drain_player(defender_index, DUEL_DRAIN);
+
+        let victim = [team_id as u8, player_id as u8];
+        let aggression = teams[opponent_team].players[defender_index].skills.aggression;
+
         // --- POSSESSION CHANGE ON FAILURE ---
         if !success {
             ball_possession[0] = opponent_team as u8;
             ball_possession[1] = defender_index as u8;
+
+            last_pass_player[0] = 255;
+            last_pass_player[1] = 255;
+
+            Self::injury(
+                teams,
+                ball_possession,
+                last_pass_player,
+                victim,
+                aggression,
+                false,
+                events,
+                minute,
+                game_result,
+                rng
+            );
+            return success;
+        }
+
+        // --- BEATEN DEFENDER MAY STOP THE ATTACKER WITH A FOUL ---
+        if Self::is_foul(teams, opponent_team, defender_index, minute, game_result, rng) {
+            let fouled = &teams[team_id].players[player_id];
+            let zone = FreeKickZone::from_position(&fouled.current_position);
+            let offender = [opponent_team as u8, defender_index as u8];
+
+            let in_the_box = Self::is_penalty(
+                &teams[opponent_team].players[defender_index],
+                fouled,
+                rng
+            );
+
+            Self::foul(teams, offender, victim, events, minute, game_result, rng);
+            Self::injury(
+                teams,
+                ball_possession,
+                last_pass_player,
+                victim,
+                aggression,
+                true,
+                events,
+                minute,
+                game_result,
+                rng
+            );
+
+            if in_the_box {
+                let taker = teams[team_id].set_piece_taker(SetPiece::Penalty);
+                let goalkeeper = Self::goalkeeper_index(&teams[opponent_team]);
+
+                EventRecord::push(events, minute, MatchEvent::Penalty {
+                    taker: EventPlayer::new(teams, team_id, taker),
+                    goalkeeper: EventPlayer::new(teams, opponent_team, goalkeeper),
+                    outcome: PenaltyOutcome::Awarded,
+                });
+
+                ball_possession[1] = taker as u8;
+                Self::penalty(teams, ball_possession, last_pass_player, events, minute, game_result, rng);
+                return success;
+            }
+
+            Self::free_kick(
+                teams,
+                ball_possession,
+                last_pass_player,
+                events,
+                minute,
+                game_result,
+                zone,
+                rng
+            );
+        } else {
+            Self::injury(
+                teams,
+                ball_possession,
+                last_pass_player,
+                victim,
+                aggression,
+                false,
+                events,
+                minute,
+                game_result,
+                rng
+            );
         }
 
         success
     }
 
-    pub fn shoot(
+    /// Counts a goal of `scorer` ([team, player]) in the score and the stats. Open play goals
+    /// (shots and headers) are assisted by the last passer of the same team, set pieces never.
+    /// `shot` is the zone and xG of an open play goal
+    #[allow(clippy::too_many_arguments)]
+    fn credit_goal(
+        teams: &mut [Team; 2],
+        last_pass_player: &[u8; 2],
+        scorer: [usize; 2],
+        kind: GoalKind,
+        shot: Option<(ShotZone, f32)>,
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        game_result: &mut GameResult
+    ) {
+        let [team_id, player_id] = scorer;
+
+        let scorer_player = &mut teams[team_id].players[player_id];
+        scorer_player.match_stats.goals += 1;
+        game_result.add_goal(team_id, scorer_player.number);
+
+        // Only the players on the pitch lose their clean sheet
+        let opponent_team = if team_id == 0 { 1 } else { 0 };
+        for player in teams[opponent_team].players.iter_mut() {
+            player.match_stats.conceded = true;
+        }
+
+        let assistant = last_pass_player[1] as usize;
+        let has_assistant =
+            matches!(kind, GoalKind::Shot | GoalKind::Header) &&
+            last_pass_player[0] as usize == team_id &&
+            assistant != player_id &&
+            assistant < teams[team_id].players.len();
+
+        if has_assistant {
+            let assistant_player = &mut teams[team_id].players[assistant];
+            assistant_player.match_stats.assists += 1;
+            game_result.teams_stats[team_id].add_assistant(assistant_player.number);
+        }
+
+        EventRecord::push(events, minute, MatchEvent::Goal {
+            scorer: EventPlayer::new(teams, team_id, player_id),
+            assistant: has_assistant.then(|| EventPlayer::new(teams, team_id, assistant)),
+            kind,
+            zone: shot.map(|(zone, _)| zone),
+            xg: shot.map(|(_, xg)| xg),
+        });
+    }
+
+    /// Rolls if the `victim` ([team, player]) of a challenge gets injured.
+    /// A soft injury lowers their skills for the rest of the match, a hard one forces
+    /// a substitution or, when none is left, leaves the team a player short.
+    #[allow(clippy::too_many_arguments)]
+    pub fn injury(
         teams: &mut [Team; 2],
         ball_possession: &mut [u8; 2],
         last_pass_player: &mut [u8; 2],
-        logs: &mut Vec<Log>,
+        victim: [u8; 2],
+        aggression: u8,
+        foul: bool,
+        events: &mut Vec<EventRecord>,
         minute: u8,
-        game_result: &mut GameResult
+        game_result: &mut GameResult,
+        rng: &mut GameRng
     ) -> bool {
-        let team_id = ball_possession[0] as usize;
-        let player_id = ball_possession[1] as usize;
+        let team_id = victim[0] as usize;
+        let player_id = victim[1] as usize;
 
-        let attacker = &teams[team_id].players[player_id];
+        let player = &teams[team_id].players[player_id];
+        let chance = injury_chance(aggression, player.energy, foul);
+        if rng.random::<f32>() * 100.0 >= chance {
+            return false;
+        }
 
-        // Attacker stats
-        let attack_score = (attacker.skills.shooting as i32) + (attacker.skills.composure as i32);
+        // A second knock on an already injured player is always hard
+        let already_injured = game_result.is_injured(team_id, &player.name, player.number);
+        let hard = already_injured || generate_seeded_number_by_range(rng, 0, 100) < HARD_INJURY_CHANCE;
 
-        // Opponent team
-        let opponent_team = if team_id == 0 { 1 } else { 0 };
+        let (injury, days) = if hard {
+            (InjuryType::Hard, HARD_INJURY_DAYS)
+        } else {
+            (InjuryType::Soft, SOFT_INJURY_DAYS)
+        };
+        let days_out = generate_seeded_number_by_range(rng, days.0, days.1);
 
-        // Find goalkeeper
-        let goalkeeper_index = teams[opponent_team].players
-            .iter()
-            .enumerate()
-            .find(|(_, p)| matches!(p.position, Position::Goalkeeper))
-            .map(|(i, _)| i)
-            .expect("Opponent team has no goalkeeper!");
+        let player_name = player.name.clone();
+        let player_number = player.number;
 
-        let goalkeeper = &teams[opponent_team].players[goalkeeper_index];
+        EventRecord::push(events, minute, MatchEvent::Injury {
+            player: EventPlayer::new(teams, team_id, player_id),
+            injury: injury.clone(),
+            days_out,
+        });
 
-        // Goalkeeper stats
-        let gk_score =
-            (goalkeeper.skills.handling as i32) +
-            (goalkeeper.skills.intuition as i32) +
-            (goalkeeper.skills.reflexes as i32);
-
-        // Compute scoring chance
-        let difficulty = gk_score - attack_score;
-        let base_scoring_chance = (50 - difficulty).clamp(5, 85);
-        let scoring_chance = (
-            (base_scoring_chance as f32) * Self::stamina_factor(attacker)
-        ).round() as u8;
+        game_result.add_injury(Injury {
+            team_index: team_id as u8,
+            player_name,
+            player_number,
+            minute,
+            injury,
+            days_out,
+        });
 
-        let roll = generate_number_by_range(0, 100);
+        if !hard {
+            teams[team_id].players[player_id].apply_soft_injury();
+            return true;
+        }
 
-        if roll < (scoring_chance as u8) {
-            // --- GOAL ---
-            logs.push(Log {
-                player_name: attacker.name.clone(),
-                player_number: attacker.number,
-                minute,
-                team_name: teams[team_id].name.clone(),
-                description: "goal.shoot".to_string(),
+        let player_out = EventPlayer::new(teams, team_id, player_id);
+        if teams[team_id].injury_substitution(player_id, minute).is_some() {
+            EventRecord::push(events, minute, MatchEvent::Substitution {
+                player_out,
+                player_in: EventPlayer::new(teams, team_id, player_id),
+                reason: SubstitutionReason::Injury,
             });
+        } else if teams[team_id].players.len() > MIN_PLAYERS_ON_PITCH {
+            teams[team_id].players[player_id].match_stats.leave(minute);
+            teams[team_id].withdraw_injured(player_id);
+            Self::shift_after_removal(teams, ball_possession, last_pass_player, team_id, player_id, rng);
+        } else {
+            // Nobody left to replace them, the player limps on
+            teams[team_id].players[player_id].apply_soft_injury();
+        }
+
+        true
+    }
+
+    /// Keeps the ball and the last passer pointing at the right players after
+    /// `removed` left the pitch of `team_id`
+    fn shift_after_removal(
+        teams: &[Team; 2],
+        ball_possession: &mut [u8; 2],
+        last_pass_player: &mut [u8; 2],
+        team_id: usize,
+        removed: usize,
+        rng: &mut GameRng
+    ) {
+        if last_pass_player[0] as usize == team_id {
+            if last_pass_player[1] as usize == removed {
+                last_pass_player[0] = 255;
+                last_pass_player[1] = 255;
+            } else if last_pass_player[1] as usize > removed {
+                last_pass_player[1] -= 1;
+            }
+        }
+
+        if ball_possession[0] as usize == team_id {
+            if ball_possession[1] as usize == removed {
+                // Play restarts with a teammate of the injured player
+                ball_possession[1] = generate_seeded_number_by_range(
+                    rng,
+                    0,
+                    (teams[team_id].players.len() - 1) as u8
+                );
+            } else if ball_possession[1] as usize > removed {
+                ball_possession[1] -= 1;
+            }
+        }
+    }
+
+    /// Rolls if a beaten defender commits a foul, based on their aggression,
+    /// their defensive style and the match state
+    fn is_foul(
+        teams: &[Team; 2],
+        team_id: usize,
+        player_id: usize,
+        minute: u8,
+        game_result: &GameResult,
+        rng: &mut GameRng
+    ) -> bool {
+        let defender = &teams[team_id].players[player_id];
+        let opponent_team = if team_id == 0 { 1 } else { 0 };
+
+        let style = defender.instructions.defensive_style();
+        let booked = game_result.is_booked(team_id, &defender.name, defender.number);
+        let losing_late = minute >= 70 && game_result.score[team_id] < game_result.score[opponent_team];
+
+        let chance =
+            foul_chance(defender.skills.aggression, &style, booked, losing_late) +
+            pressing_foul_bonus(&teams[team_id].tactics.pressing);
+        let roll = generate_seeded_number_by_range(rng, 0, 100);
+
+        (roll as f32) < chance
+    }
+
+    /// Rolls if a foul on an attacker in a striker, wing or attacking midfield
+    /// position happened inside the box
+    fn is_penalty(defender: &Player, attacker: &Player, rng: &mut GameRng) -> bool {
+        let attacking_position = matches!(
+            attacker.current_position,
+            Position::Striker | Position::Left_Wing | Position::Right_Wing | Position::Attacking_Midfield
+        );
+        if !attacking_position {
+            return false;
+        }
+
+        let chance = penalty_chance(defender.skills.aggression, attacker.skills.dribbling);
+        let roll = generate_seeded_number_by_range(rng, 0, 100);
+
+        (roll as f32) < chance
+    }
+
+    /// Resolves a foul of `offender` on `victim` ([team, player]), the fouled team keeps the ball.
+    /// The referee may book the player, a second yellow or a straight red sends them off.
+    pub fn foul(
+        teams: &mut [Team; 2],
+        offender: [u8; 2],
+        victim: [u8; 2],
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        game_result: &mut GameResult,
+        rng: &mut GameRng
+    ) {
+        let team_id = offender[0] as usize;
+        let player_id = offender[1] as usize;
+
+        teams[team_id].players[player_id].match_stats.fouls += 1;
+        game_result.teams_stats[team_id].fouls += 1;
+
+        EventRecord::push(events, minute, MatchEvent::Foul {
+            offender: EventPlayer::new(teams, team_id, player_id),
+            victim: EventPlayer::new(teams, victim[0] as usize, victim[1] as usize),
+        });
+
+        let player = &teams[team_id].players[player_id];
+
+        let aggression = player.skills.aggression;
+        let style = player.instructions.defensive_style();
+        let booked = game_result.is_booked(team_id, &player.name, player.number);
+        let can_send_off = teams[team_id].players.len() > MIN_PLAYERS_ON_PITCH;
+
+        let reckless = generate_seeded_number_by_range(rng, 0, 100) < reckless_chance(&style);
+        let card_roll = generate_seeded_number_by_range(rng, 0, 100) as f32;
+
+        let card = if reckless && can_send_off && Self::check_red(aggression, rng) {
+            Some(BookingType::Red)
+        } else if card_roll < yellow_chance(aggression, &style) {
+            if booked && can_send_off {
+                Some(BookingType::SecondYellow)
+            } else if !booked {
+                Some(BookingType::Yellow)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        let card = match card {
+            Some(card) => card,
+            None => {
+                return;
+            }
+        };
+
+        EventRecord::push(events, minute, MatchEvent::Card {
+            player: EventPlayer::new(teams, team_id, player_id),
+            card: card.clone(),
+        });
+
+        game_result.add_booking(Booking {
+            team_index: team_id as u8,
+            player_name: player.name.clone(),
+            player_number: player.number,
+            minute,
+            card: card.clone(),
+        });
+
+        let match_stats = &mut teams[team_id].players[player_id].match_stats;
+        match card {
+            BookingType::Yellow => {
+                match_stats.yellow_cards += 1;
+            }
+            BookingType::SecondYellow => {
+                match_stats.yellow_cards += 1;
+                match_stats.red_cards += 1;
+            }
+            BookingType::Red => {
+                match_stats.red_cards += 1;
+            }
+        }
+
+        if card.is_sending_off() {
+            match_stats.leave(minute);
+            teams[team_id].send_off(player_id);
+        }
+    }
+
+    /// Shot of the ball holder from a zone picked by their current position
+    pub fn shoot(
+        teams: &mut [Team; 2],
+        ball_possession: &mut [u8; 2],
+        last_pass_player: &mut [u8; 2],
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        game_result: &mut GameResult,
+        rng: &mut GameRng
+    ) -> bool {
+        let shooter = &teams[ball_possession[0] as usize].players[ball_possession[1] as usize];
+        let zone = Self::shot_zone(&shooter.current_position, rng);
+
+        Self::shoot_from(teams, ball_possession, last_pass_player, zone, events, minute, game_result, rng)
+    }
+
+    fn shot_zone(position: &Position, rng: &mut GameRng) -> ShotZone {
+        let zones = position.shot_zones();
+        let total: u32 = zones.iter().map(|(_, w)| *w as u32).sum();
+        let roll = generate_seeded_number_by_range(rng, 0, (total - 1) as u8) as u32;
+
+        let mut sum = 0;
+        for (zone, weight) in zones {
+            sum += *weight as u32;
+            if roll < sum {
+                return *zone;
+            }
+        }
+
+        ShotZone::LongRange
+    }
+
+    /// Shot of the ball holder from `zone`, its xG is added to the team and the shooter
+    #[allow(clippy::too_many_arguments)]
+    fn shoot_from(
+        teams: &mut [Team; 2],
+        ball_possession: &mut [u8; 2],
+        last_pass_player: &mut [u8; 2],
+        zone: ShotZone,
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        game_result: &mut GameResult,
+        rng: &mut GameRng
+    ) -> bool {
+        let team_id = ball_possession[0] as usize;
+        let player_id = ball_possession[1] as usize;
+        let opponent_team = if team_id == 0 { 1 } else { 0 };
+        let goalkeeper_index = Self::goalkeeper_index(&teams[opponent_team]);
+
+        let (xg, scored) = Self::finish(teams, ball_possession, &zone, rng);
+
+        game_result.teams_stats[team_id].shoots += 1;
+        game_result.teams_stats[team_id].xg += xg;
+        teams[team_id].players[player_id].match_stats.shoots += 1;
+        teams[team_id].players[player_id].match_stats.xg += xg;
+        let attacker = &teams[team_id].players[player_id];
 
+        if scored {
+            // --- GOAL ---
             println!("GOAL by {}, {}!", attacker.name, teams[team_id].name);
-            game_result.score[team_id] += 1;
+            game_result.teams_stats[team_id].shoots_suc += 1;
+            teams[team_id].players[player_id].match_stats.shoots_suc += 1;
+            Self::credit_goal(
+                teams,
+                last_pass_player,
+                [team_id, player_id],
+                GoalKind::Shot,
+                Some((zone, xg)),
+                events,
+                minute,
+                game_result
+            );
 
             // Clear last passer
             last_pass_player[0] = 255;
@@ -222,36 +722,187 @@ impl Actions {
         last_pass_player[0] = 255;
         last_pass_player[1] = 255;
 
-        // Decide if rebound or corner
-        let outcome = generate_number_by_range(0, 100);
-
-        if outcome < 30 {
-            // GK saves → corner
-            logs.push(Log {
-                player_name: attacker.name.clone(),
-                player_number: attacker.number,
+        // Wide of the goal or on target for the goalkeeper to save
+        if generate_seeded_number_by_range(rng, 0, 100) < ON_TARGET_CHANCE {
+            game_result.teams_stats[team_id].shoots_suc += 1;
+            teams[team_id].players[player_id].match_stats.shoots_suc += 1;
+
+            Self::save(
+                teams,
+                ball_possession,
+                last_pass_player,
+                [team_id, player_id],
+                zone,
+                xg,
+                false,
+                events,
                 minute,
-                team_name: teams[team_id].name.clone(),
-                description: "corner.shoot".to_string(),
-            });
-
-            Self::corner(teams, ball_possession, last_pass_player, logs, minute, game_result);
+                game_result,
+                rng
+            );
         } else {
             // Ball stays in play → rebound
-            logs.push(Log {
-                player_name: attacker.name.clone(),
-                player_number: attacker.number,
-                minute,
-                team_name: teams[team_id].name.clone(),
-                description: "failed.shoot".to_string(),
+            EventRecord::push(events, minute, MatchEvent::Shot {
+                shooter: EventPlayer::new(teams, team_id, player_id),
+                goalkeeper: EventPlayer::new(teams, opponent_team, goalkeeper_index),
+                header: false,
+                zone,
+                xg,
+                outcome: ShotOutcome::Wide,
             });
 
-            Self::rebound(teams, ball_possession, last_pass_player);
+            Self::rebound(teams, ball_possession, last_pass_player, rng);
         }
 
         true
     }
 
+    /// The goalkeeper stops a shot on target of `shooter`, holding it, parrying it into
+    /// play or out for a corner, or spilling it for an attacker to follow up
+    #[allow(clippy::too_many_arguments)]
+    fn save(
+        teams: &mut [Team; 2],
+        ball_possession: &mut [u8; 2],
+        last_pass_player: &mut [u8; 2],
+        shooter: [usize; 2],
+        zone: ShotZone,
+        xg: f32,
+        header: bool,
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        game_result: &mut GameResult,
+        rng: &mut GameRng
+    ) {
+        let [team_id, player_id] = shooter;
+        let opponent_team = if team_id == 0 { 1 } else { 0 };
+        let goalkeeper_index = Self::goalkeeper_index(&teams[opponent_team]);
+        let goalkeeper = &teams[opponent_team].players[goalkeeper_index];
+
+        let save = save_type(
+            goalkeeper.skills.handling,
+            goalkeeper.skills.reflexes,
+            energy_performance_factor(goalkeeper.energy),
+            xg,
+            [rng.random::<f32>(), rng.random::<f32>(), rng.random::<f32>()]
+        );
+
+        let outcome = match save {
+            SaveType::Catch => ShotOutcome::Caught,
+            SaveType::ParryInPlay => ShotOutcome::Parried,
+            SaveType::ParryCorner => ShotOutcome::ParriedCorner,
+            SaveType::Fumble => ShotOutcome::Fumbled,
+        };
+        EventRecord::push(events, minute, MatchEvent::Shot {
+            shooter: EventPlayer::new(teams, team_id, player_id),
+            goalkeeper: EventPlayer::new(teams, opponent_team, goalkeeper_index),
+            header,
+            zone,
+            xg,
+            outcome,
+        });
+
+        last_pass_player[0] = 255;
+        last_pass_player[1] = 255;
+        ball_possession[0] = team_id as u8;
+        ball_possession[1] = player_id as u8;
+
+        match save {
+            SaveType::Catch => {
+                Self::credit_save(teams, game_result, opponent_team, goalkeeper_index);
+                ball_possession[0] = opponent_team as u8;
+                ball_possession[1] = goalkeeper_index as u8;
+            }
+            SaveType::ParryInPlay => {
+                Self::credit_save(teams, game_result, opponent_team, goalkeeper_index);
+                Self::rebound(teams, ball_possession, last_pass_player, rng);
+            }
+            SaveType::ParryCorner => {
+                Self::credit_save(teams, game_result, opponent_team, goalkeeper_index);
+                Self::corner(teams, ball_possession, last_pass_player, events, minute, game_result, rng);
+            }
+            SaveType::Fumble => {
+                // The loose ball falls to an attacker in the box
+                let attackers: Vec<usize> = teams[team_id].players
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, p)| is_box_position(&p.current_position))
+                    .map(|(i, _)| i)
+                    .collect();
+
+                match attackers.choose(rng).copied() {
+                    Some(attacker) => {
+                        ball_possession[1] = attacker as u8;
+                        Self::shoot_from(
+                            teams,
+                            ball_possession,
+                            last_pass_player,
+                            ShotZone::InsideBox,
+                            events,
+                            minute,
+                            game_result,
+                            rng
+                        );
+                    }
+                    None => Self::rebound(teams, ball_possession, last_pass_player, rng),
+                }
+            }
+        }
+    }
+
+    fn credit_save(teams: &mut [Team; 2], game_result: &mut GameResult, team_id: usize, goalkeeper: usize) {
+        game_result.teams_stats[team_id].saves += 1;
+        teams[team_id].players[goalkeeper].match_stats.saves += 1;
+    }
+
+    fn credit_claim(teams: &mut [Team; 2], game_result: &mut GameResult, team_id: usize, goalkeeper: usize) {
+        game_result.teams_stats[team_id].claims += 1;
+        teams[team_id].players[goalkeeper].match_stats.claims += 1;
+    }
+
+    /// Counts a set piece shot, `on_target` for a goal or a save
+    fn credit_shot(
+        teams: &mut [Team; 2],
+        game_result: &mut GameResult,
+        team_id: usize,
+        shooter: usize,
+        on_target: bool
+    ) {
+        game_result.teams_stats[team_id].shoots += 1;
+        teams[team_id].players[shooter].match_stats.shoots += 1;
+        if on_target {
+            game_result.teams_stats[team_id].shoots_suc += 1;
+            teams[team_id].players[shooter].match_stats.shoots_suc += 1;
+        }
+    }
+
+    /// The goalkeeper in possession builds up short or kicks long, from their `kicking`,
+    /// the tempo of the team and the pressing of the opponent
+    pub fn distribute(
+        teams: &mut [Team; 2],
+        ball_possession: &mut [u8; 2],
+        last_pass_player: &mut [u8; 2],
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        game_result: &mut GameResult,
+        rng: &mut GameRng
+    ) -> bool {
+        let team_id = ball_possession[0] as usize;
+        let opponent_team = if team_id == 0 { 1 } else { 0 };
+        let goalkeeper = &teams[team_id].players[ball_possession[1] as usize];
+
+        let chance = long_kick_chance(
+            goalkeeper.skills.kicking,
+            &teams[team_id].tactics.tempo,
+            &teams[opponent_team].tactics.pressing
+        );
+
+        if (generate_seeded_number_by_range(rng, 0, 99) as f32) < chance {
+            Self::long_pass(teams, ball_possession, events, minute, last_pass_player, game_result, rng)
+        } else {
+            Self::pass(teams, ball_possession, last_pass_player, events, minute, game_result, rng)
+        }
+    }
+
     pub fn advance(teams: &mut [Team; 2], ball_possession: &mut [u8; 2]) -> bool {
         let player = Self::get_player(teams, ball_possession);
 
@@ -270,125 +921,459 @@ impl Actions {
             Position::Striker => Position::Striker,
         };
 
-        true
+        true
+    }
+
+    /// A good cross starts an aerial duel in the box, a bad one is cut out by
+    /// a defender, goes wide for a goal kick or is deflected for a corner
+    pub fn cross(
+        teams: &mut [Team; 2],
+        ball_possession: &mut [u8; 2],
+        last_pass_player: &mut [u8; 2],
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        game_result: &mut GameResult,
+        rng: &mut GameRng
+    ) -> bool {
+        let crosser = Self::get_player(teams, ball_possession);
+
+        let cross_quality =
+            (crosser.skills.crossing as f32) * 0.65 +
+            (crosser.skills.vision as f32) * 0.25 +
+            (crosser.skills.composure as f32) * 0.1;
+
+        let base_chance = (cross_quality / 100.0).clamp(0.05, 0.9);
+        let success_chance = (base_chance * Self::fitness_factor(crosser)).clamp(0.05, 0.9);
+
+        let roll = (generate_seeded_number_by_range(rng, 0, 100) as f32) / 100.0;
+
+        let success = roll <= success_chance;
+
+        let stats = &mut game_result.teams_stats[ball_possession[0] as usize];
+        stats.cross += 1;
+        if success {
+            stats.cross_suc += 1;
+        }
+
+        // --- LOGGING ---
+        EventRecord::push(events, minute, MatchEvent::Cross {
+            crosser: EventPlayer::new(teams, ball_possession[0] as usize, ball_possession[1] as usize),
+            player: None,
+            outcome: if success { CrossOutcome::Completed } else { CrossOutcome::Failed },
+        });
+
+        if success {
+            last_pass_player[0] = ball_possession[0];
+            last_pass_player[1] = ball_possession[1];
+            Self::aerial_duel(teams, ball_possession, last_pass_player, events, minute, game_result, rng);
+        } else {
+            Self::failed_cross(teams, ball_possession, last_pass_player, events, minute, game_result, rng);
+        }
+
+        success
+    }
+
+    fn aerial_strength(player: &Player) -> f32 {
+        aerial_score(
+            player.height_cm,
+            player.skills.physical,
+            player.skills.aggression,
+            energy_performance_factor(player.energy)
+        )
+    }
+
+    /// Best player in the air of `team` playing in one of the positions of `filter`,
+    /// any outfield player when nobody plays there
+    fn best_in_the_air(team: &Team, excluded: Option<usize>, filter: fn(&Position) -> bool) -> Option<usize> {
+        let best = |filter: &dyn Fn(&Position) -> bool| {
+            team.players
+                .iter()
+                .enumerate()
+                .filter(|(i, p)| Some(*i) != excluded && filter(&p.current_position))
+                .max_by(|(_, a), (_, b)| Self::aerial_strength(a).total_cmp(&Self::aerial_strength(b)))
+                .map(|(i, _)| i)
+        };
+
+        best(&filter).or_else(|| best(&|p: &Position| *p != Position::Goalkeeper))
+    }
+
+    /// The best attacker in the air goes for the cross of the ball holder against the
+    /// best header of the defensive line. The winner heads at goal or down to a teammate,
+    /// or clears the ball, sometimes out for a corner
+    fn aerial_duel(
+        teams: &mut [Team; 2],
+        ball_possession: &mut [u8; 2],
+        last_pass_player: &mut [u8; 2],
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        game_result: &mut GameResult,
+        rng: &mut GameRng
+    ) {
+        let attacking_team = ball_possession[0] as usize;
+        let defending_team = if attacking_team == 0 { 1 } else { 0 };
+        let crosser = ball_possession[1] as usize;
+
+        // The goalkeeper can come off the line and take the cross
+        let goalkeeper_index = Self::goalkeeper_index(&teams[defending_team]);
+        let goalkeeper = &teams[defending_team].players[goalkeeper_index];
+        let claim = claim_chance(
+            goalkeeper.skills.handling,
+            goalkeeper.height_cm,
+            energy_performance_factor(goalkeeper.energy)
+        );
+        if (generate_seeded_number_by_range(rng, 0, 99) as f32) < claim {
+            EventRecord::push(events, minute, MatchEvent::Cross {
+                crosser: EventPlayer::new(teams, attacking_team, crosser),
+                player: Some(EventPlayer::new(teams, defending_team, goalkeeper_index)),
+                outcome: CrossOutcome::Claimed,
+            });
+            Self::credit_claim(teams, game_result, defending_team, goalkeeper_index);
+
+            last_pass_player[0] = 255;
+            last_pass_player[1] = 255;
+            ball_possession[0] = defending_team as u8;
+            ball_possession[1] = goalkeeper_index as u8;
+            return;
+        }
+
+        let attacker = Self::best_in_the_air(&teams[attacking_team], Some(crosser), is_box_position);
+        let marker = Self::best_in_the_air(&teams[defending_team], None, is_defensive_line);
+        let (Some(attacker), Some(marker)) = (attacker, marker) else {
+            Self::rebound(teams, ball_possession, last_pass_player, rng);
+            return;
+        };
+
+        let attacker_strength = Self::aerial_strength(&teams[attacking_team].players[attacker]);
+        let marker_strength = Self::aerial_strength(&teams[defending_team].players[marker]);
+        let marker_aggression = teams[defending_team].players[marker].skills.aggression;
+
+        teams[attacking_team].drain_player(attacker, DUEL_DRAIN);
+        teams[defending_team].drain_player(marker, DUEL_DRAIN);
+
+        let roll = generate_seeded_number_by_range(rng, 0, 100) as f32;
+        let attacker_wins = roll < aerial_win_chance(attacker_strength, marker_strength);
+
+        let corner = if attacker_wins {
+            Self::header(
+                teams,
+                ball_possession,
+                last_pass_player,
+                attacker,
+                attacker_strength,
+                events,
+                minute,
+                game_result,
+                rng
+            );
+            false
+        } else {
+            Self::clear_cross(teams, ball_possession, last_pass_player, marker, events, minute, rng)
+        };
+
+        // Jumping for the ball can hurt the attacker, the corner rolls its own challenge
+        Self::injury(
+            teams,
+            ball_possession,
+            last_pass_player,
+            [attacking_team as u8, attacker as u8],
+            marker_aggression,
+            false,
+            events,
+            minute,
+            game_result,
+            rng
+        );
+
+        if corner {
+            ball_possession[0] = attacking_team as u8;
+            Self::corner(teams, ball_possession, last_pass_player, events, minute, game_result, rng);
+        }
+    }
+
+    /// The `header` who won the aerial duel heads at goal or down to a teammate in the box,
+    /// or to the nearest one when nobody else is attacking the box
+    #[allow(clippy::too_many_arguments)]
+    fn header(
+        teams: &mut [Team; 2],
+        ball_possession: &mut [u8; 2],
+        last_pass_player: &mut [u8; 2],
+        header: usize,
+        strength: f32,
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        game_result: &mut GameResult,
+        rng: &mut GameRng
+    ) {
+        let team_id = ball_possession[0] as usize;
+        let opponent_team = if team_id == 0 { 1 } else { 0 };
+
+        // --- HEADED DOWN TO A TEAMMATE ---
+        if generate_seeded_number_by_range(rng, 0, 100) >= HEADER_SHOT_CHANCE {
+            let distances: Vec<(usize, u8)> = teams[team_id].players
+                .iter()
+                .enumerate()
+                .filter(|(i, p)| *i != header && p.position != Position::Goalkeeper)
+                .map(|(i, p)| (i, box_distance(&p.current_position)))
+                .collect();
+            let nearest = distances
+                .iter()
+                .map(|(_, distance)| *distance)
+                .min();
+            let teammates: Vec<usize> = distances
+                .iter()
+                .filter(|(_, distance)| Some(*distance) == nearest)
+                .map(|(i, _)| *i)
+                .collect();
+
+            EventRecord::push(events, minute, MatchEvent::Cross {
+                crosser: EventPlayer::new(teams, team_id, ball_possession[1] as usize),
+                player: Some(EventPlayer::new(teams, team_id, header)),
+                outcome: CrossOutcome::HeadedDown,
+            });
+
+            last_pass_player[0] = team_id as u8;
+            last_pass_player[1] = header as u8;
+            ball_possession[1] = teammates.choose(rng).copied().unwrap_or(header) as u8;
+            return;
+        }
+
+        // --- HEADER AT GOAL ---
+        let goalkeeper_index = Self::goalkeeper_index(&teams[opponent_team]);
+        let goalkeeper = &teams[opponent_team].players[goalkeeper_index];
+        let goalkeeper_score =
+            (((goalkeeper.skills.handling as f32) + (goalkeeper.skills.reflexes as f32)) / 2.0) *
+            energy_performance_factor(goalkeeper.energy);
+
+        let shooting = teams[team_id].players[header].skills.shooting;
+        let chance = header_scoring_chance(shooting, strength, goalkeeper_score);
+        let xg = header_scoring_chance(shooting, strength, REFERENCE_KEEPER) / 100.0;
+
+        game_result.teams_stats[team_id].shoots += 1;
+        game_result.teams_stats[team_id].xg += xg;
+        teams[team_id].players[header].match_stats.shoots += 1;
+        teams[team_id].players[header].match_stats.xg += xg;
+
+        let roll = generate_seeded_number_by_range(rng, 0, 100) as f32;
+        let scored = roll < chance;
+        let on_target = scored || generate_seeded_number_by_range(rng, 0, 100) < 50;
+
+        if on_target {
+            game_result.teams_stats[team_id].shoots_suc += 1;
+            teams[team_id].players[header].match_stats.shoots_suc += 1;
+        }
+
+        if on_target && !scored {
+            Self::save(
+                teams,
+                ball_possession,
+                last_pass_player,
+                [team_id, header],
+                ShotZone::InsideBox,
+                xg,
+                true,
+                events,
+                minute,
+                game_result,
+                rng
+            );
+            return;
+        }
+
+        if scored {
+            Self::credit_goal(
+                teams,
+                last_pass_player,
+                [team_id, header],
+                GoalKind::Header,
+                Some((ShotZone::InsideBox, xg)),
+                events,
+                minute,
+                game_result
+            );
+        } else {
+            EventRecord::push(events, minute, MatchEvent::Shot {
+                shooter: EventPlayer::new(teams, team_id, header),
+                goalkeeper: EventPlayer::new(teams, opponent_team, goalkeeper_index),
+                header: true,
+                zone: ShotZone::InsideBox,
+                xg,
+                outcome: ShotOutcome::Wide,
+            });
+        }
+
+        // After a goal or a goal kick the goalkeeper has the ball
+        last_pass_player[0] = 255;
+        last_pass_player[1] = 255;
+        ball_possession[0] = opponent_team as u8;
+        ball_possession[1] = goalkeeper_index as u8;
     }
 
-    pub fn cross(
+    /// The `marker` wins the aerial duel and clears the cross, returns true when
+    /// the ball goes out for a corner
+    fn clear_cross(
         teams: &mut [Team; 2],
         ball_possession: &mut [u8; 2],
-        logs: &mut Vec<Log>,
-        minute: u8
+        last_pass_player: &mut [u8; 2],
+        marker: usize,
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        rng: &mut GameRng
     ) -> bool {
-        let crosser = Self::get_player(teams, ball_possession);
+        let defending_team = if ball_possession[0] == 0 { 1 } else { 0 };
 
-        let cross_quality =
-            (crosser.skills.crossing as f32) * 0.65 +
-            (crosser.skills.vision as f32) * 0.25 +
-            (crosser.skills.composure as f32) * 0.1;
+        EventRecord::push(events, minute, MatchEvent::Cross {
+            crosser: EventPlayer::new(teams, ball_possession[0] as usize, ball_possession[1] as usize),
+            player: Some(EventPlayer::new(teams, defending_team, marker)),
+            outcome: CrossOutcome::Cleared,
+        });
 
-        let base_chance = (cross_quality / 100.0).clamp(0.05, 0.9);
-        let success_chance = (base_chance * Self::stamina_factor(crosser)).clamp(0.05, 0.9);
+        last_pass_player[0] = 255;
+        last_pass_player[1] = 255;
 
-        let roll = (generate_number_by_range(0, 100) as f32) / 100.0;
+        if generate_seeded_number_by_range(rng, 0, 100) < CLEARED_CORNER_CHANCE {
+            return true;
+        }
 
-        let success = roll <= success_chance;
+        ball_possession[0] = defending_team as u8;
+        ball_possession[1] = marker as u8;
+        Self::reset_position(teams, ball_possession);
 
-        // --- LOGGING ---
-        logs.push(Log {
-            player_name: crosser.name.clone(),
-            player_number: crosser.number,
-            minute,
-            team_name: teams[ball_possession[0] as usize].name.clone(),
-            description: if success {
-                "success.cross".to_string()
-            } else {
-                "failed.cross".to_string()
-            },
+        false
+    }
+
+    /// A bad cross is cut out by the best header of the defensive line,
+    /// goes wide for a goal kick or is deflected for a corner
+    fn failed_cross(
+        teams: &mut [Team; 2],
+        ball_possession: &mut [u8; 2],
+        last_pass_player: &mut [u8; 2],
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        game_result: &mut GameResult,
+        rng: &mut GameRng
+    ) {
+        let attacking_team = ball_possession[0] as usize;
+        let defending_team = if attacking_team == 0 { 1 } else { 0 };
+
+        last_pass_player[0] = 255;
+        last_pass_player[1] = 255;
+
+        let roll = generate_seeded_number_by_range(rng, 0, 100);
+        if roll >= INTERCEPTED_CROSS_CHANCE + WIDE_CROSS_CHANCE {
+            EventRecord::push(events, minute, MatchEvent::Cross {
+                crosser: EventPlayer::new(teams, attacking_team, ball_possession[1] as usize),
+                player: None,
+                outcome: CrossOutcome::Corner,
+            });
+            Self::corner(teams, ball_possession, last_pass_player, events, minute, game_result, rng);
+            return;
+        }
+
+        let (receiver, outcome) = if roll < INTERCEPTED_CROSS_CHANCE {
+            let defender = Self::best_in_the_air(&teams[defending_team], None, is_defensive_line);
+            (defender.unwrap_or(0), CrossOutcome::Intercepted)
+        } else {
+            (Self::goalkeeper_index(&teams[defending_team]), CrossOutcome::GoalKick)
+        };
+
+        EventRecord::push(events, minute, MatchEvent::Cross {
+            crosser: EventPlayer::new(teams, attacking_team, ball_possession[1] as usize),
+            player: Some(EventPlayer::new(teams, defending_team, receiver)),
+            outcome,
         });
 
-        success
+        ball_possession[0] = defending_team as u8;
+        ball_possession[1] = receiver as u8;
+        Self::reset_position(teams, ball_possession);
     }
 
     pub fn long_pass(
         teams: &mut [Team; 2],
         ball_possession: &mut [u8; 2],
-        logs: &mut Vec<Log>,
+        events: &mut Vec<EventRecord>,
         minute: u8,
-        last_pass_player: &mut [u8; 2]
+        last_pass_player: &mut [u8; 2],
+        game_result: &mut GameResult,
+        rng: &mut GameRng
     ) -> bool {
         let team_id = ball_possession[0] as usize;
         let passer_index = ball_possession[1] as usize;
 
-        let passer = &teams[team_id].players[passer_index];
+        let passer = teams[team_id].players[passer_index].clone();
 
         // --- 1. LONG PASS QUALITY ---
+        // Goalkeepers kick the ball long
+        let passing = if passer.current_position == Position::Goalkeeper {
+            passer.skills.kicking
+        } else {
+            passer.skills.passing
+        };
         let lp_quality =
-            (passer.skills.passing as f32) * 0.55 +
+            (passing as f32) * 0.55 +
             (passer.skills.vision as f32) * 0.3 +
             (passer.skills.composure as f32) * 0.15;
 
         let base_chance = (lp_quality / 100.0).clamp(0.03, 0.85);
-        let success_chance = base_chance * Self::stamina_factor(passer);
+        let success_chance = base_chance * Self::fitness_factor(&passer);
 
         // --- 2. TARGET SELECTION ---
-        let players_len = teams[team_id].players.len();
+        let (_, receiver_index) = Self::select_pass_target(teams, ball_possession, true, rng);
+        let receiver_index = receiver_index as usize;
 
-        let mut weights: Vec<f32> = Vec::new();
-        let mut indices: Vec<usize> = Vec::new();
-
-        for i in 0..players_len {
-            if i == passer_index {
-                continue;
-            }
-
-            let mut weight = ((passer_index as i32) - (i as i32)).abs() as f32;
-
-            if i == 0 {
-                weight *= 0.35;
-            }
-
-            if weight < 0.5 {
-                weight = 0.5;
-            }
+        // --- 3. LONG PASS SUCCESS ROLL ---
+        let roll = (generate_seeded_number_by_range(rng, 0, 100) as f32) / 100.0;
+        let success = roll <= success_chance;
 
-            weights.push(weight);
-            indices.push(i);
-        }
+        game_result.teams_stats[team_id].long_pass += 1;
+        game_result.teams_stats[team_id].passes += 1;
+        teams[team_id].players[passer_index].match_stats.passes += 1;
 
-        let total_weight: f32 = weights.iter().sum();
-        let mut rnd = ((generate_number_by_range(0, 100) as f32) / 100.0) * total_weight;
+        let call = if success {
+            Self::offside_call(teams, team_id, receiver_index, false, rng)
+        } else {
+            OffsideCall::Onside
+        };
 
-        let mut receiver_index = indices[0];
-        for (i, w) in weights.iter().enumerate() {
-            if rnd < *w {
-                receiver_index = indices[i];
-                break;
-            }
-            rnd -= *w;
+        if call == OffsideCall::Offside {
+            Self::flag_offside(
+                teams,
+                ball_possession,
+                last_pass_player,
+                receiver_index,
+                true,
+                events,
+                minute,
+                game_result,
+                rng
+            );
+            return false;
         }
 
-        // --- 3. LONG PASS SUCCESS ROLL ---
-        let roll = (generate_number_by_range(0, 100) as f32) / 100.0;
-        let success = roll <= success_chance;
-
         if success {
+            game_result.teams_stats[team_id].long_pass_suc += 1;
+            game_result.teams_stats[team_id].passes_suc += 1;
+            teams[team_id].players[passer_index].match_stats.passes_suc += 1;
+
             // Update possession
             ball_possession[1] = receiver_index as u8;
 
-            logs.push(Log {
-                player_name: passer.name.clone(),
-                player_number: passer.number,
-                minute,
-                team_name: teams[team_id].name.clone(),
-                description: "success.long_pass".to_string(),
+            EventRecord::push(events, minute, MatchEvent::Pass {
+                passer: EventPlayer::new(teams, team_id, passer_index),
+                receiver: Some(EventPlayer::new(teams, team_id, receiver_index)),
+                long: true,
+                outcome: PassOutcome::Completed,
             });
 
             // Player must control the long pass
-            let control_success = Self::control(teams, ball_possession, logs, minute);
+            let control_success = Self::control(teams, ball_possession, last_pass_player, events, minute, game_result, rng);
 
             if control_success {
                 last_pass_player[0] = team_id as u8;
-                last_pass_player[1] = receiver_index as u8;
+                last_pass_player[1] = passer_index as u8;
+
+                if call == OffsideCall::TrapBeaten {
+                    Self::beat_offside_trap(teams, ball_possession, last_pass_player, events, minute, game_result, rng);
+                }
             }
 
             // If control fails, rebound() is automatically handled by control()
@@ -399,12 +1384,14 @@ impl Actions {
         ball_possession[0] = if team_id == 0 { 1 } else { 0 };
         ball_possession[1] = 0;
 
-        logs.push(Log {
-            player_name: passer.name.clone(),
-            player_number: passer.number,
-            minute,
-            team_name: teams[team_id].name.clone(),
-            description: "failed.long_pass".to_string(),
+        last_pass_player[0] = 255;
+        last_pass_player[1] = 255;
+
+        EventRecord::push(events, minute, MatchEvent::Pass {
+            passer: EventPlayer::new(teams, team_id, passer_index),
+            receiver: None,
+            long: true,
+            outcome: PassOutcome::Failed,
         });
 
         false
@@ -413,8 +1400,11 @@ impl Actions {
     pub fn control(
         teams: &mut [Team; 2],
         ball_possession: &mut [u8; 2],
-        logs: &mut Vec<Log>,
-        minute: u8
+        last_pass_player: &mut [u8; 2],
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        game_result: &mut GameResult,
+        rng: &mut GameRng
     ) -> bool {
         let receiver = Self::get_player(teams, ball_possession);
 
@@ -425,104 +1415,146 @@ impl Actions {
             (receiver.skills.physical as f32) * 0.15;
 
         let base_chance = (control_quality / 100.0).clamp(0.1, 0.95);
-        let success_chance = base_chance * Self::stamina_factor(receiver);
+        let success_chance = base_chance * Self::fitness_factor(receiver);
 
-        let roll = (generate_number_by_range(0, 100) as f32) / 100.0;
+        let roll = (generate_seeded_number_by_range(rng, 0, 100) as f32) / 100.0;
         let success = roll <= success_chance;
 
+        let stats = &mut game_result.teams_stats[ball_possession[0] as usize];
+        stats.controls += 1;
+        receiver.match_stats.controls += 1;
+        if success {
+            stats.controls_suc += 1;
+            receiver.match_stats.controls_suc += 1;
+        }
+
         // --- LOG CONTROL ATTEMPT ---
-        logs.push(Log {
-            player_name: receiver.name.clone(),
-            player_number: receiver.number,
-            minute,
-            team_name: teams[ball_possession[0] as usize].name.clone(),
-            description: if success {
-                "success.control".to_string()
-            } else {
-                "failed.control".to_string()
-            },
+        EventRecord::push(events, minute, MatchEvent::Control {
+            player: EventPlayer::new(teams, ball_possession[0] as usize, ball_possession[1] as usize),
+            success,
         });
 
+        // Receiving the ball also tires the player
+        teams[ball_possession[0] as usize].drain_player(ball_possession[1] as usize, ACTION_DRAIN);
+
         if success {
             // The player successfully controls the ball.
             return true;
         }
 
         // --- FAILED CONTROL: TRIGGER REBOUND ---
-        Self::rebound(teams, ball_possession, &mut [0u8; 2]);
+        Self::rebound(teams, ball_possession, last_pass_player, rng);
 
         false
     }
 
-    pub fn finish(teams: &mut [Team; 2], ball_possession: &mut [u8; 2]) -> bool {
-        let shooter = Self::get_player(teams, ball_possession);
+    /// Rolls a shot of the ball holder from `zone` against the opponent goalkeeper,
+    /// returns the xG of the shot and true on a goal
+    pub fn finish(
+        teams: &[Team; 2],
+        ball_possession: &[u8; 2],
+        zone: &ShotZone,
+        rng: &mut GameRng
+    ) -> (f32, bool) {
+        let team_id = ball_possession[0] as usize;
+        let opponent_team = if team_id == 0 { 1 } else { 0 };
+        let shooter = &teams[team_id].players[ball_possession[1] as usize];
+
+        let quality = shot_quality(
+            zone,
+            shooter.skills.shooting,
+            shooter.skills.finishing,
+            shooter.skills.composure
+        );
+        let xg = shot_xg(zone, quality, Self::fitness_factor(shooter));
+
+        let goalkeeper = &teams[opponent_team].players[Self::goalkeeper_index(&teams[opponent_team])];
+        let keeper =
+            (((goalkeeper.skills.handling as f32) +
+                (goalkeeper.skills.intuition as f32) +
+                (goalkeeper.skills.reflexes as f32)) /
+                3.0) *
+            energy_performance_factor(goalkeeper.energy);
+
+        (xg, rng.random::<f32>() < goal_chance(xg, keeper))
+    }
+
+    /// Picks the receiver of a pass from the passer's pass matrix, weighted by the
+    /// position each teammate is playing and lowered when they are marked
+    pub fn select_pass_target(
+        teams: &[Team; 2],
+        ball_possession: &[u8; 2],
+        long_pass: bool,
+        rng: &mut GameRng
+    ) -> (u8, u8) {
+        let team_id = ball_possession[0] as usize;
+        let passer_index = ball_possession[1] as usize;
+        let opponent_team = if team_id == 0 { 1 } else { 0 };
 
-        let fin_quality =
-            (shooter.skills.finishing as f32) * 0.65 +
-            (shooter.skills.composure as f32) * 0.25 +
-            (shooter.skills.control as f32) * 0.1;
+        let passer = &teams[team_id].players[passer_index];
+        let matrix = if long_pass {
+            passer.current_position.long_passes()
+        } else {
+            passer.current_position.passes()
+        };
 
-        let base_chance = (fin_quality / 100.0).clamp(0.02, 0.8);
-        let success_chance = base_chance * Self::stamina_factor(shooter);
+        let mut weights: Vec<f32> = Vec::new();
+        let mut indices: Vec<usize> = Vec::new();
 
-        let roll = (generate_number_by_range(0, 100) as f32) / 100.0;
+        for (i, receiver) in teams[team_id].players.iter().enumerate() {
+            if i == passer_index {
+                continue;
+            }
 
-        roll >= success_chance
-    }
+            let marker_position = Self::matchup_position(&receiver.current_position);
+            let marked = teams[opponent_team].players
+                .iter()
+                .find(|p| p.current_position == marker_position)
+                .is_some_and(|marker| {
+                    is_marked(
+                        (marker.skills.defense as f32) * energy_performance_factor(marker.energy),
+                        receiver.skills.speed,
+                        receiver.skills.vision
+                    )
+                });
 
-    fn select_pass_target(teams: &mut [Team; 2], ball_possession: &[u8; 2]) -> (u8, u8) {
-        use rand_distr::{ Distribution, Normal };
-        use rand::Rng;
+            weights.push(receiver_weight(pass_weight(matrix, &receiver.current_position), marked));
+            indices.push(i);
+        }
 
-        let team_id = ball_possession[0] as usize;
-        let num_players = teams[team_id].players.len();
-        let current_player = ball_possession[1] as f32;
-
-        let std_dev = 1.5; // tweak for tighter/wider selection
-        let normal = Normal::new(current_player, std_dev).unwrap();
-        let mut rng = rand::thread_rng();
-        let mut target;
-
-        loop {
-            // Sample from Gaussian
-            target = normal.sample(&mut rng).round() as i32;
-
-            // Clamp to valid range
-            if target >= 0 && (target as usize) < num_players {
-                // Low probability for goalkeeper if current player is not GK
-                if target == 0 && current_player != 0.0 {
-                    if rng.gen_bool(0.1) {
-                        // 10% chance to pass to goalkeeper
-                        return (team_id as u8, 0);
-                    } else {
-                        continue; // resample
-                    }
-                }
+        let total_weight: f32 = weights.iter().sum();
+        let mut rnd = rng.random::<f32>() * total_weight;
 
-                // Do not allow passing to self
-                if (target as u8) != ball_possession[1] {
-                    return (team_id as u8, target as u8);
-                }
+        let mut receiver_index = indices[indices.len() - 1];
+        for (i, w) in weights.iter().enumerate() {
+            if rnd < *w {
+                receiver_index = indices[i];
+                break;
             }
+            rnd -= *w;
         }
+
+        (team_id as u8, receiver_index as u8)
     }
 
     fn rebound(
         teams: &mut [Team; 2],
         ball_possession: &mut [u8; 2],
-        last_pass_player: &mut [u8; 2]
+        last_pass_player: &mut [u8; 2],
+        rng: &mut GameRng
     ) {
         // Clear last passer
         last_pass_player[0] = 255;
         last_pass_player[1] = 255;
 
         // 50/50 chance → same team or opponent team recovers ball
-        let same_team_recovers = generate_number_by_range(0, 100) < 50;
+        let same_team_recovers = generate_seeded_number_by_range(rng, 0, 100) < 50;
 
         if same_team_recovers {
             // SAME TEAM RECOVERS
             let team_id = ball_possession[0] as usize;
-            let new_player = generate_number_by_range(
+            let new_player = generate_seeded_number_by_range(
+                rng,
                 0,
                 (teams[team_id].players.len() as u8) - 1
             ) as u8;
@@ -532,7 +1564,8 @@ impl Actions {
         } else {
             // OPPONENT TEAM RECOVERS
             let opponent_team = if ball_possession[0] == 0 { 1 } else { 0 };
-            let new_player = generate_number_by_range(
+            let new_player = generate_seeded_number_by_range(
+                rng,
                 0,
                 (teams[opponent_team].players.len() as u8) - 1
             ) as u8;
@@ -548,9 +1581,10 @@ impl Actions {
         teams: &mut [Team; 2],
         ball_possession: &mut [u8; 2],
         last_pass_player: &mut [u8; 2],
-        logs: &mut Vec<Log>,
+        events: &mut Vec<EventRecord>,
         minute: u8,
-        game_result: &mut GameResult
+        game_result: &mut GameResult,
+        rng: &mut GameRng
     ) -> bool {
         let team_id = ball_possession[0] as usize;
         let player_id = ball_possession[1] as usize;
@@ -570,11 +1604,13 @@ impl Actions {
 
         // --- Shooter & GK ability ---
         let shoot_value =
-            (shooter.skills.finishing as f32) * 0.6 + (shooter.skills.composure as f32) * 0.4;
+            ((shooter.skills.finishing as f32) * 0.6 + (shooter.skills.composure as f32) * 0.4) *
+            Self::fitness_factor(shooter);
         let gk_value =
-            (goalkeeper.skills.intuition as f32) * 0.4 +
-            (goalkeeper.skills.handling as f32) * 0.3 +
-            (goalkeeper.skills.reflexes as f32) * 0.3;
+            ((goalkeeper.skills.intuition as f32) * 0.4 +
+                (goalkeeper.skills.handling as f32) * 0.3 +
+                (goalkeeper.skills.reflexes as f32) * 0.3) *
+            energy_performance_factor(goalkeeper.energy);
 
         let mut score_chance = 0.6 + (shoot_value - gk_value) * 0.01;
         let mut rebound_chance = 0.15 + (gk_value - shoot_value) * 0.005;
@@ -586,17 +1622,25 @@ impl Actions {
         let rebound_pct = (rebound_chance * 100.0).round() as i32;
         let mut corner_pct = (100 - score_pct - rebound_pct).max(5);
 
-        let roll = generate_number_by_range(0, 100);
+        let roll = generate_seeded_number_by_range(rng, 0, 100);
+
+        game_result.teams_stats[team_id].penalties += 1;
+        // A penalty that doesn't go in is saved, it's always on target
+        Self::credit_shot(teams, game_result, team_id, player_id, true);
 
         if roll < (score_pct as u8) {
             // --- GOAL ---
-            logs.push(Log {
-                player_name: shooter.name.clone(),
-                player_number: shooter.number,
+            game_result.teams_stats[team_id].penalties_goals += 1;
+            Self::credit_goal(
+                teams,
+                last_pass_player,
+                [team_id, player_id],
+                GoalKind::Penalty,
+                None,
+                events,
                 minute,
-                team_name: teams[team_id].name.clone(),
-                description: "goal.penalty".to_string(),
-            });
+                game_result
+            );
 
             last_pass_player[0] = 255;
             last_pass_player[1] = 255;
@@ -605,30 +1649,33 @@ impl Actions {
             ball_possession[0] = opponent_team as u8;
             ball_possession[1] = gk_index as u8;
 
-            true
-        } else if roll < (score_pct as u8) + (rebound_pct as u8) {
+            return true;
+        }
+
+        // Every missed penalty is a save of the goalkeeper
+        Self::credit_save(teams, game_result, opponent_team, gk_index);
+        let taker = EventPlayer::new(teams, team_id, player_id);
+        let goalkeeper = EventPlayer::new(teams, opponent_team, gk_index);
+
+        if roll < (score_pct as u8) + (rebound_pct as u8) {
             // --- REBOUND ---
-            logs.push(Log {
-                player_name: shooter.name.clone(),
-                player_number: shooter.number,
-                minute,
-                team_name: teams[team_id].name.clone(),
-                description: "rebound.penalty".to_string(),
+            EventRecord::push(events, minute, MatchEvent::Penalty {
+                taker,
+                goalkeeper,
+                outcome: PenaltyOutcome::Rebound,
             });
 
-            Self::rebound(teams, ball_possession, last_pass_player);
+            Self::rebound(teams, ball_possession, last_pass_player, rng);
             false
         } else {
             // --- CORNER ---
-            logs.push(Log {
-                player_name: shooter.name.clone(),
-                player_number: shooter.number,
-                minute,
-                team_name: teams[team_id].name.clone(),
-                description: "corner.penalty".to_string(),
+            EventRecord::push(events, minute, MatchEvent::Penalty {
+                taker,
+                goalkeeper,
+                outcome: PenaltyOutcome::Corner,
             });
 
-            Self::corner(teams, ball_possession, last_pass_player, logs, minute, game_result)
+            Self::corner(teams, ball_possession, last_pass_player, events, minute, game_result, rng)
         }
     }
 
@@ -657,25 +1704,328 @@ impl Actions {
         }
     }
 
-    pub fn check_red(defender_aggression: u8) -> bool {
-        let foul_roll = generate_number_by_range(0, 100);
+    pub fn check_red(defender_aggression: u8, rng: &mut GameRng) -> bool {
+        let foul_roll = generate_seeded_number_by_range(rng, 0, 100);
         foul_roll < defender_aggression / 4
     }
 
+    /// Free kick for the team in possession, the designated taker shoots over the wall,
+    /// crosses into the box or restarts short depending on the `zone` of the foul
+    #[allow(clippy::too_many_arguments)]
+    pub fn free_kick(
+        teams: &mut [Team; 2],
+        ball_possession: &mut [u8; 2],
+        last_pass_player: &mut [u8; 2],
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        game_result: &mut GameResult,
+        zone: FreeKickZone,
+        rng: &mut GameRng
+    ) -> bool {
+        let attacking_team = ball_possession[0] as usize;
+        let defending_team = if attacking_team == 0 { 1 } else { 0 };
+
+        let taker_index = match zone {
+            FreeKickZone::Indirect => Self::goalkeeper_index(&teams[attacking_team]),
+            _ => teams[attacking_team].set_piece_taker(SetPiece::FreeKick),
+        };
+        ball_possession[1] = taker_index as u8;
+
+        let taker = teams[attacking_team].players[taker_index].clone();
+        let taker_event = EventPlayer::new(teams, attacking_team, taker_index);
+
+        game_result.teams_stats[attacking_team].free_kicks += 1;
+
+        let free_kick_event = |outcome: FreeKickOutcome| MatchEvent::FreeKick {
+            taker: taker_event.clone(),
+            outcome,
+        };
+
+        match zone {
+            FreeKickZone::Short => {
+                // --- SHORT RESTART: THE TAKER PLAYS IT TO A TEAMMATE ---
+                EventRecord::push(events, minute, free_kick_event(FreeKickOutcome::Short));
+                Self::pass(teams, ball_possession, last_pass_player, events, minute, game_result, rng)
+            }
+            FreeKickZone::Indirect => {
+                // --- OFFSIDE: THE GOALKEEPER RESTARTS TO A TEAMMATE ---
+                EventRecord::push(events, minute, free_kick_event(FreeKickOutcome::Indirect));
+                Self::pass(teams, ball_possession, last_pass_player, events, minute, game_result, rng)
+            }
+            FreeKickZone::Cross => {
+                // --- CROSS INTO THE BOX ---
+                let delivery =
+                    ((taker.skills.kicking as f32) * 0.4 +
+                        (taker.skills.crossing as f32) * 0.4 +
+                        (taker.skills.composure as f32) * 0.2) *
+                    Self::fitness_factor(&taker);
+                let delivery_chance = (delivery / 100.0).clamp(0.05, 0.9) * 100.0;
+
+                let roll = generate_seeded_number_by_range(rng, 0, 100) as f32;
+
+                if roll >= delivery_chance {
+                    EventRecord::push(events, minute, free_kick_event(FreeKickOutcome::Cleared));
+                    Self::rebound(teams, ball_possession, last_pass_player, rng);
+                    Self::aerial_challenge(
+                        teams,
+                        ball_possession,
+                        last_pass_player,
+                        attacking_team,
+                        events,
+                        minute,
+                        game_result,
+                        rng
+                    );
+                    return false;
+                }
+
+                // A good delivery is fought for in the air like a cross, the taker assists the header
+                EventRecord::push(events, minute, free_kick_event(FreeKickOutcome::Delivered));
+                last_pass_player[0] = attacking_team as u8;
+                last_pass_player[1] = taker_index as u8;
+
+                let goals = game_result.score[attacking_team];
+                Self::aerial_duel(teams, ball_possession, last_pass_player, events, minute, game_result, rng);
+                game_result.score[attacking_team] > goals
+            }
+            FreeKickZone::Direct => {
+                // --- DIRECT SHOT OVER THE WALL ---
+                let quality =
+                    ((taker.skills.kicking as f32) * 0.4 +
+                        (taker.skills.shooting as f32) * 0.35 +
+                        (taker.skills.composure as f32) * 0.25) *
+                    Self::fitness_factor(&taker);
+
+                let gk_index = Self::goalkeeper_index(&teams[defending_team]);
+                let goalkeeper = &teams[defending_team].players[gk_index];
+                let gk_value =
+                    ((goalkeeper.skills.reflexes as f32) * 0.4 +
+                        (goalkeeper.skills.handling as f32) * 0.3 +
+                        (goalkeeper.skills.intuition as f32) * 0.3) *
+                    energy_performance_factor(goalkeeper.energy);
+
+                // Tall walls block more, good strikers curl it over
+                let wall_chance = (
+                    22.0 +
+                    (teams[defending_team].average_height() - 180.0) * 0.5 -
+                    ((taker.skills.kicking as f32) - 50.0) * 0.15
+                ).clamp(8.0, 35.0);
+                let on_target_chance = ((quality / 100.0) * 75.0).clamp(20.0, 80.0);
+                let goal_chance = (20.0 + (quality - gk_value)).clamp(3.0, 50.0);
+
+                let wall_roll = generate_seeded_number_by_range(rng, 0, 100) as f32;
+                if wall_roll < wall_chance {
+                    Self::credit_shot(teams, game_result, attacking_team, taker_index, false);
+                    EventRecord::push(events, minute, free_kick_event(FreeKickOutcome::Wall));
+                    Self::rebound(teams, ball_possession, last_pass_player, rng);
+                    return false;
+                }
+
+                let target_roll = generate_seeded_number_by_range(rng, 0, 100) as f32;
+                let on_target = target_roll < on_target_chance;
+                Self::credit_shot(teams, game_result, attacking_team, taker_index, on_target);
+
+                if !on_target {
+                    // Wide or over the bar → goal kick
+                    EventRecord::push(events, minute, free_kick_event(FreeKickOutcome::Wide));
+                    last_pass_player[0] = 255;
+                    last_pass_player[1] = 255;
+                    ball_possession[0] = defending_team as u8;
+                    ball_possession[1] = gk_index as u8;
+                    return false;
+                }
+
+                let goal_roll = generate_seeded_number_by_range(rng, 0, 100) as f32;
+                if goal_roll < goal_chance {
+                    game_result.teams_stats[attacking_team].free_kicks_goals += 1;
+                    Self::credit_goal(
+                        teams,
+                        last_pass_player,
+                        [attacking_team, taker_index],
+                        GoalKind::FreeKick,
+                        None,
+                        events,
+                        minute,
+                        game_result
+                    );
+                    Self::kick_off(teams, ball_possession, last_pass_player, defending_team);
+                    return true;
+                }
+
+                EventRecord::push(events, minute, free_kick_event(FreeKickOutcome::Saved));
+                Self::credit_save(teams, game_result, defending_team, gk_index);
+
+                // The goalkeeper holds it or pushes it out for a corner
+                if generate_seeded_number_by_range(rng, 0, 100) < 50 {
+                    last_pass_player[0] = 255;
+                    last_pass_player[1] = 255;
+                    ball_possession[0] = defending_team as u8;
+                    ball_possession[1] = gk_index as u8;
+                    false
+                } else {
+                    Self::corner(teams, ball_possession, last_pass_player, events, minute, game_result, rng)
+                }
+            }
+        }
+    }
+
+    /// Assistant referee call on a pass of `team_id` to `receiver`. Forward runs can be caught
+    /// offside, more often against an offside trap, and a receiver faster than the defensive
+    /// line can run past the trap
+    fn offside_call(
+        teams: &[Team; 2],
+        team_id: usize,
+        receiver: usize,
+        through_ball: bool,
+        rng: &mut GameRng
+    ) -> OffsideCall {
+        let player = &teams[team_id].players[receiver];
+        if !can_be_offside(&player.current_position, through_ball) {
+            return OffsideCall::Onside;
+        }
+
+        let defending_team = if team_id == 0 { 1 } else { 0 };
+        let line: Vec<&Player> = teams[defending_team].players
+            .iter()
+            .filter(|p| is_defensive_line(&p.current_position))
+            .collect();
+        if line.is_empty() {
+            return OffsideCall::Onside;
+        }
+
+        let line_speed =
+            line
+                .iter()
+                .map(|p| (p.skills.speed as f32) * energy_performance_factor(p.energy))
+                .sum::<f32>() / (line.len() as f32);
+        let trap_size = line
+            .iter()
+            .filter(|p| p.instructions.plays_offside_trap())
+            .count();
+        let receiver_speed = (player.skills.speed as f32) * energy_performance_factor(player.energy);
+        let defensive_line = &teams[defending_team].tactics.defensive_line;
+
+        let roll = generate_seeded_number_by_range(rng, 0, 100) as f32;
+        if roll < offside_chance(receiver_speed, line_speed, trap_size, defensive_line, through_ball) {
+            return OffsideCall::Offside;
+        }
+
+        let roll = generate_seeded_number_by_range(rng, 0, 100) as f32;
+        if roll < trap_beaten_chance(receiver_speed, line_speed, trap_size, defensive_line) {
+            OffsideCall::TrapBeaten
+        } else {
+            OffsideCall::Onside
+        }
+    }
+
+    /// The `receiver` of the team in possession is caught offside,
+    /// the defending team restarts with an indirect free kick
+    #[allow(clippy::too_many_arguments)]
+    fn flag_offside(
+        teams: &mut [Team; 2],
+        ball_possession: &mut [u8; 2],
+        last_pass_player: &mut [u8; 2],
+        receiver: usize,
+        long: bool,
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        game_result: &mut GameResult,
+        rng: &mut GameRng
+    ) {
+        let team_id = ball_possession[0] as usize;
+        let defending_team = if team_id == 0 { 1 } else { 0 };
+
+        game_result.teams_stats[team_id].offsides += 1;
+
+        EventRecord::push(events, minute, MatchEvent::Pass {
+            passer: EventPlayer::new(teams, team_id, ball_possession[1] as usize),
+            receiver: Some(EventPlayer::new(teams, team_id, receiver)),
+            long,
+            outcome: PassOutcome::Offside,
+        });
+
+        last_pass_player[0] = 255;
+        last_pass_player[1] = 255;
+        ball_possession[0] = defending_team as u8;
+
+        Self::free_kick(
+            teams,
+            ball_possession,
+            last_pass_player,
+            events,
+            minute,
+            game_result,
+            FreeKickZone::Indirect,
+            rng
+        );
+    }
+
+    /// The ball holder ran past the offside trap and is through on goal
+    fn beat_offside_trap(
+        teams: &mut [Team; 2],
+        ball_possession: &mut [u8; 2],
+        last_pass_player: &mut [u8; 2],
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        game_result: &mut GameResult,
+        rng: &mut GameRng
+    ) {
+        let team_id = ball_possession[0] as usize;
+        EventRecord::push(events, minute, MatchEvent::OffsideTrapBeaten {
+            player: EventPlayer::new(teams, team_id, ball_possession[1] as usize),
+        });
+
+        Self::shoot_from(
+            teams,
+            ball_possession,
+            last_pass_player,
+            ShotZone::OneOnOne,
+            events,
+            minute,
+            game_result,
+            rng
+        );
+    }
+
+    fn goalkeeper_index(team: &Team) -> usize {
+        team.players
+            .iter()
+            .enumerate()
+            .find(|(_, p)| matches!(p.position, Position::Goalkeeper))
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    }
+
+    /// After a goal the conceding team restarts from its goalkeeper
+    fn kick_off(
+        teams: &mut [Team; 2],
+        ball_possession: &mut [u8; 2],
+        last_pass_player: &mut [u8; 2],
+        conceding_team: usize
+    ) {
+        last_pass_player[0] = 255;
+        last_pass_player[1] = 255;
+
+        ball_possession[0] = conceding_team as u8;
+        ball_possession[1] = Self::goalkeeper_index(&teams[conceding_team]) as u8;
+    }
+
     pub fn corner(
         teams: &mut [Team; 2],
         ball_possession: &mut [u8; 2],
         last_pass_player: &mut [u8; 2],
-        logs: &mut Vec<Log>,
+        events: &mut Vec<EventRecord>,
         minute: u8,
-        game_result: &mut GameResult
+        game_result: &mut GameResult,
+        rng: &mut GameRng
     ) -> bool {
         let attacking_team = ball_possession[0] as usize;
         let defending_team = if attacking_team == 0 { 1 } else { 0 };
 
-        //? wold be nice to take the corner kicker form the instructions of the player
+        game_result.teams_stats[attacking_team].corners += 1;
+
         last_pass_player[0] = attacking_team as u8;
-        last_pass_player[1] = 7;
+        last_pass_player[1] = teams[attacking_team].set_piece_taker(SetPiece::Corner) as u8;
 
         // Extract heights
         let mut atk_heights: Vec<u8> = teams[attacking_team].players
@@ -708,69 +2058,122 @@ impl Actions {
 
         let scoring_chance = (height_advantage / 1.8 + 30.0).clamp(2.0, 6.0) as u8;
 
-        let roll = generate_number_by_range(0, 100);
+        let roll = generate_seeded_number_by_range(rng, 0, 100);
 
         // --- GOAL ---
         if roll < scoring_chance {
             if last_pass_player[0] != 255 {
-                let shooter =
-                    &teams[last_pass_player[0] as usize].players[last_pass_player[1] as usize];
-                logs.push(Log {
-                    player_name: shooter.name.clone(),
-                    player_number: shooter.number,
+                let taker = [last_pass_player[0] as usize, last_pass_player[1] as usize];
+                println!(
+                    "GOAL from CORNER by {}, {}!",
+                    teams[taker[0]].players[taker[1]].name,
+                    teams[attacking_team].name
+                );
+
+                game_result.teams_stats[attacking_team].corners_goals += 1;
+                Self::credit_goal(
+                    teams,
+                    last_pass_player,
+                    taker,
+                    GoalKind::Corner,
+                    None,
+                    events,
                     minute,
-                    team_name: teams[attacking_team].name.clone(),
-                    description: "goal.corner".to_string(),
-                });
-
-                game_result.score[attacking_team] += 1;
-                println!("GOAL from CORNER by {}, {}!", shooter.name, teams[attacking_team].name);
+                    game_result
+                );
             }
+            Self::kick_off(teams, ball_possession, last_pass_player, defending_team);
             return true;
         }
 
         // --- DEFENDER OR GK WINS ---
-        let outcome = generate_number_by_range(0, 100);
+        let outcome = generate_seeded_number_by_range(rng, 0, 100);
+        let gk_index = teams[defending_team].players
+            .iter()
+            .enumerate()
+            .find(|(_, p)| matches!(p.position, Position::Goalkeeper))
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        let corner_event = |teams: &[Team; 2], outcome: CornerOutcome| MatchEvent::Corner {
+            taker: EventPlayer::new(teams, last_pass_player[0] as usize, last_pass_player[1] as usize),
+            goalkeeper: EventPlayer::new(teams, defending_team, gk_index),
+            outcome,
+        };
 
         if outcome < 40 {
             // 40% → GK catches the ball
-            let gk_index = teams[defending_team].players
-                .iter()
-                .enumerate()
-                .find(|(_, p)| matches!(p.position, Position::Goalkeeper))
-                .map(|(i, _)| i)
-                .unwrap_or(0);
-
             ball_possession[0] = defending_team as u8;
             ball_possession[1] = gk_index as u8;
+            Self::credit_claim(teams, game_result, defending_team, gk_index);
 
             if last_pass_player[0] != 255 {
-                let shooter =
-                    &teams[last_pass_player[0] as usize].players[last_pass_player[1] as usize];
-                logs.push(Log {
-                    player_name: shooter.name.clone(),
-                    player_number: shooter.number,
-                    minute,
-                    team_name: teams[attacking_team].name.clone(),
-                    description: "saved.corner".to_string(),
-                });
+                EventRecord::push(events, minute, corner_event(teams, CornerOutcome::Claimed));
             }
         } else {
             // 60% → REBOUND BATTLE
             if last_pass_player[0] != 255 {
-                let shooter =
-                    &teams[last_pass_player[0] as usize].players[last_pass_player[1] as usize];
-                logs.push(Log {
-                    player_name: shooter.name.clone(),
-                    player_number: shooter.number,
-                    minute,
-                    team_name: teams[attacking_team].name.clone(),
-                    description: "rebound.corner".to_string(),
-                });
+                EventRecord::push(events, minute, corner_event(teams, CornerOutcome::Rebound));
             }
-            Self::rebound(teams, ball_possession, last_pass_player);
+            Self::rebound(teams, ball_possession, last_pass_player, rng);
         }
 
+        Self::aerial_challenge(
+            teams,
+            ball_possession,
+            last_pass_player,
+            attacking_team,
+            events,
+            minute,
+            game_result,
+            rng
+        );
+
         false
     }
+
+    /// Players jumping for a ball in the box can get hurt, rolls an injury
+    /// for a random outfield attacker against a random defender
+    #[allow(clippy::too_many_arguments)]
+    fn aerial_challenge(
+        teams: &mut [Team; 2],
+        ball_possession: &mut [u8; 2],
+        last_pass_player: &mut [u8; 2],
+        attacking_team: usize,
+        events: &mut Vec<EventRecord>,
+        minute: u8,
+        game_result: &mut GameResult,
+        rng: &mut GameRng
+    ) {
+        let defending_team = if attacking_team == 0 { 1 } else { 0 };
+
+        let attackers: Vec<usize> = teams[attacking_team].players
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.position != Position::Goalkeeper)
+            .map(|(i, _)| i)
+            .collect();
+        let Some(attacker) = attackers.choose(rng).copied() else {
+            return;
+        };
+
+        let defender = generate_seeded_number_by_range(
+            rng,
+            0,
+            (teams[defending_team].players.len() - 1) as u8
+        ) as usize;
+        let aggression = teams[defending_team].players[defender].skills.aggression;
+
+        Self::injury(
+            teams,
+            ball_possession,
+            last_pass_player,
+            [attacking_team as u8, attacker as u8],
+            aggression,
+            false,
+            events,
+            minute,
+            game_result,
+            rng
+        );
+    }
 }
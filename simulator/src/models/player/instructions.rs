@@ -9,7 +9,7 @@ pub enum OffensiveInstruction {
     LongBall,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub enum DefensiveInstruction {
     Destroy,
@@ -22,13 +22,40 @@ pub enum DefensiveInstruction {
 pub struct Instructions {
     pub offensive: Vec<OffensiveInstruction>,
     pub defensive: Vec<DefensiveInstruction>,
+    // Penalty taker priority, 1 is the first choice and 0 means not designated
+    #[serde(default)]
+    pub penalty_kicker: u8,
+    // Corner taker priority, 1 is the first choice and 0 means not designated
+    #[serde(default)]
+    pub corner_kicker: u8,
+    // Free kick taker priority, 1 is the first choice and 0 means not designated
+    #[serde(default)]
+    pub free_kick_kicker: u8,
 }
 
 impl Instructions {
+    /// How hard the player defends, the first of Destroy, Normal or Passive in
+    /// `defensive` wins and players without any defend as Normal
+    pub fn defensive_style(&self) -> DefensiveInstruction {
+        self.defensive
+            .iter()
+            .find(|instr| **instr != DefensiveInstruction::Offside)
+            .cloned()
+            .unwrap_or(DefensiveInstruction::Normal)
+    }
+
+    /// Players with the Offside instruction step up together to catch the attackers offside
+    pub fn plays_offside_trap(&self) -> bool {
+        self.defensive.contains(&DefensiveInstruction::Offside)
+    }
+
     pub fn generate_empty_instructions() -> Self {
         Self {
             offensive: vec![],
             defensive: vec![],
+            penalty_kicker: 0,
+            corner_kicker: 0,
+            free_kick_kicker: 0,
         }
     }
 }
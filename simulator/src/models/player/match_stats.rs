@@ -0,0 +1,62 @@
+use serde::{ Deserialize, Serialize };
+
+/// Stat line of a player in a single match, the backend merges it into `Stats` after each fixture
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct PlayerMatchStats {
+    pub player_name: String,
+    pub player_number: u8,
+    pub minutes_played: u8,
+    pub goals: i32,
+    pub assists: i32,
+    pub passes: i32,
+    pub passes_suc: i32,
+    pub shoots: i32,
+    pub shoots_suc: i32,
+    // Expected goals of the shots and headers of the player
+    pub xg: f32,
+    pub dribbles_won: i32,
+    pub dribbles_lost: i32,
+    pub controls: i32,
+    pub controls_suc: i32,
+    pub tackles: i32,
+    pub saves: i32,
+    pub claims: i32,
+    // Goalkeepers whose team conceded no goals while they were on the pitch
+    pub clean_sheet: bool,
+    pub fouls: i32,
+    pub yellow_cards: i32,
+    pub red_cards: i32,
+    // 0 - 10, computed from the contributions when the match ends
+    pub rating: f32,
+
+    // Minutes the player entered and left the pitch
+    #[serde(skip)]
+    pub played: bool,
+    #[serde(skip)]
+    pub minute_in: u8,
+    #[serde(skip)]
+    pub minute_out: Option<u8>,
+    // The team conceded a goal while the player was on the pitch
+    #[serde(skip)]
+    pub conceded: bool,
+}
+
+impl PlayerMatchStats {
+    pub fn enter(&mut self, minute: u8) {
+        self.played = true;
+        self.minute_in = minute;
+    }
+
+    pub fn leave(&mut self, minute: u8) {
+        self.minute_out = Some(minute);
+    }
+
+    /// Minutes on the pitch, players still playing stay until `final_minute`
+    pub fn minutes(&self, final_minute: u8) -> u8 {
+        if !self.played {
+            return 0;
+        }
+
+        self.minute_out.unwrap_or(final_minute).saturating_sub(self.minute_in)
+    }
+}
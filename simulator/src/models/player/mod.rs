@@ -3,6 +3,7 @@ pub mod skills;
 pub mod instructions;
 pub mod status;
 pub mod stats;
+pub mod match_stats;
 pub mod position;
 pub mod countries;
 pub mod cards;
@@ -5,6 +5,7 @@ use crate::models::player::skills::Skills;
 use crate::models::player::status::Status;
 use crate::models::player::instructions::Instructions;
 use crate::models::player::stats::Stats;
+use crate::models::player::match_stats::PlayerMatchStats;
 use crate::models::player::position::Position;
 use crate::models::player::cards::Card;
 use crate::models::player::countries::Country;
@@ -13,6 +14,8 @@ use crate::utils::generate_random_number::generate_number_by_range;
 
 use crate::logics::player::generate_random_name::generate_random_name;
 use crate::logics::player::generate_random_country::generate_random_country;
+use crate::logics::player::fatigue::{ FULL_ENERGY, stamina_drain_factor };
+use crate::logics::player::injuries::SOFT_INJURY_SKILL_FACTOR;
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Player {
@@ -30,6 +33,18 @@ pub struct Player {
     pub status: Status,
     pub instructions: Instructions,
     pub stats: Stats,
+
+    // Energy during the match (0-100), drained by every minute and action
+    #[serde(default = "default_energy")]
+    pub energy: f32,
+
+    // Stat line of the match being played
+    #[serde(skip)]
+    pub match_stats: PlayerMatchStats,
+}
+
+fn default_energy() -> f32 {
+    FULL_ENERGY
 }
 
 impl Player {
@@ -56,6 +71,8 @@ impl Player {
             stats,
             status,
             instructions,
+            energy: FULL_ENERGY,
+            match_stats: PlayerMatchStats::default(),
         }
     }
 
@@ -65,6 +82,28 @@ impl Player {
         }
     }
 
+    /// Drains the energy of the player, slowed by their stamina and the team `drain_factor`
+    pub fn drain_energy(&mut self, amount: f32, drain_factor: f32) {
+        let drain = amount * stamina_drain_factor(self.skills.stamina) * drain_factor;
+        self.energy = (self.energy - drain).clamp(0.0, FULL_ENERGY);
+    }
+
+    /// A player playing on with a soft injury loses part of their physical
+    /// and technical level for the rest of the match
+    pub fn apply_soft_injury(&mut self) {
+        let skills = &mut self.skills;
+        for skill in [
+            &mut skills.speed,
+            &mut skills.physical,
+            &mut skills.dribbling,
+            &mut skills.shooting,
+            &mut skills.defense,
+            &mut skills.reflexes,
+        ] {
+            *skill = ((*skill as f32) * SOFT_INJURY_SKILL_FACTOR).round() as u8;
+        }
+    }
+
      pub fn apply_country_bonus(&mut self, bonus: u8) {
         if bonus == 0 { return; }
         self.skills.shooting = self.skills.shooting.saturating_add(bonus);
@@ -10,6 +10,13 @@ pub struct Stats {
     pub red_cards: i32,
     pub yellow_cards: i32,
     pub total_games: i32,
+    // Goalkeeping, missing in players saved before they were tracked
+    #[serde(default)]
+    pub saves: i32,
+    #[serde(default)]
+    pub claims: i32,
+    #[serde(default)]
+    pub clean_sheets: i32,
 }
 
 impl Stats {
@@ -22,7 +29,10 @@ impl Stats {
             assists: 0,
             red_cards: 0, 
             yellow_cards: 0,
-            total_games: 0
+            total_games: 0,
+            saves: 0,
+            claims: 0,
+            clean_sheets: 0,
         }
     }
 }
@@ -1,16 +1,36 @@
-use actix_web::{post, web, HttpResponse};
+use actix_web::{get, post, web, HttpRequest, HttpResponse};
 use serde::Deserialize;
 
+use crate::handlers::game::live_game::handle_live_game;
+use crate::handlers::game::simulate_batch::handle_simulate_batch;
 use crate::handlers::game::simulate_game::handle_simulate_game;
 use crate::models::game::team::Team;
+use crate::models::game::match_mode::MatchMode;
 
 #[derive(Deserialize)]
 pub struct GameRequestBody {
     pub teams: Vec<Team>,
+    pub seed: Option<u64>,
+    #[serde(default)]
+    pub mode: MatchMode,
+    // Also return the match as the legacy `logs` next to the `events`
+    #[serde(default)]
+    pub legacy_logs: bool,
+    // Language of the commentary, no commentary when missing
+    pub lang: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct BatchRequestBody {
+    pub teams: Vec<Team>,
+    pub simulations: u32,
+    pub seed: Option<u64>,
 }
 
 pub fn init(cfg: &mut web::ServiceConfig) {
     cfg.service(simulate_game);
+    cfg.service(simulate_game_batch);
+    cfg.service(live_game);
 }
 
 #[post("/game/simulate")]
@@ -24,8 +44,48 @@ pub async fn simulate_game(body: web::Json<GameRequestBody>) -> HttpResponse {
         Err(_) => return HttpResponse::BadRequest().body("Error converting to array."),
     };
 
-    match handle_simulate_game(teams_array) {
+    match handle_simulate_game(
+        teams_array,
+        body.seed,
+        body.mode.clone(),
+        body.legacy_logs,
+        body.lang.as_deref()
+    ) {
         Ok(game_result) => HttpResponse::Ok().json(game_result),
         Err(err) => HttpResponse::BadRequest().body(err),
     }
 }
+
+#[post("/game/simulate/batch")]
+pub async fn simulate_game_batch(body: web::Json<BatchRequestBody>) -> HttpResponse {
+    if body.teams.len() != 2 {
+        return HttpResponse::BadRequest().body("This service requires 2 teams.");
+    }
+
+    let teams_array: [Team; 2] = match body.teams.clone().try_into() {
+        Ok(arr) => arr,
+        Err(_) => return HttpResponse::BadRequest().body("Error converting to array."),
+    };
+
+    let simulations = body.simulations;
+    let seed = body.seed;
+
+    // The batch is CPU bound, keep it away from the actix workers
+    let result = web::block(move || handle_simulate_batch(teams_array, simulations, seed)).await;
+
+    match result {
+        Ok(Ok(batch_result)) => HttpResponse::Ok().json(batch_result),
+        Ok(Err(err)) => HttpResponse::BadRequest().body(err),
+        Err(_) => HttpResponse::InternalServerError().body("Batch simulation was interrupted."),
+    }
+}
+
+/// Live match over a WebSocket, the first message of the client is the match to play
+#[get("/game/live")]
+pub async fn live_game(req: HttpRequest, body: web::Payload) -> Result<HttpResponse, actix_web::Error> {
+    let (response, session, stream) = actix_ws::handle(&req, body)?;
+
+    actix_web::rt::spawn(handle_live_game(session, stream));
+
+    Ok(response)
+}
@@ -1,7 +1,132 @@
+use crate::models::game::batch_result::{ BatchResult, BatchStats };
+use crate::models::game::commentary::{ Commentator, CommentaryLine, CommentaryTemplates };
 use crate::models::game::game::GameReturn;
 use crate::models::game::game::Game;
+use crate::models::game::live::LiveFrame;
+use crate::models::game::match_event::MatchEvent;
+use crate::models::game::match_mode::MatchMode;
 use crate::models::game::team::Team;
+use crate::utils::generate_random_number::generate_seed;
+use std::thread;
 
-pub fn simulate_new_game(teams: [Team; 2]) -> Result<GameReturn, String> {
-    Game::create_game(teams)
+const TOP_SCORERS: usize = 10;
+
+pub fn simulate_new_game(
+    teams: [Team; 2],
+    seed: Option<u64>,
+    mode: MatchMode,
+    legacy_logs: bool
+) -> Result<GameReturn, String> {
+    Game::create_game_with_mode(teams, seed, mode, legacy_logs)
+}
+
+/// Commentary of a simulated match, the seed of the match picks the phrasing
+pub fn comment_game(
+    game: &GameReturn,
+    templates: CommentaryTemplates,
+    team_names: [String; 2]
+) -> Vec<CommentaryLine> {
+    Commentator::new(templates, team_names, game.seed).comment_all(&game.events)
+}
+
+/// Splits a simulated match in the frames of a live match: a `Minute` for every minute with events,
+/// with the commentary of its events, and the `Summary` last.
+/// The pre-match events open the first minute and the stoppage time announcement closes its minute
+pub fn live_frames(game: GameReturn) -> Vec<LiveFrame> {
+    let mut frames = Vec::new();
+    let mut commentary = game.commentary.into_iter().peekable();
+    let mut pending = Vec::new();
+
+    for record in game.events {
+        let commentary: Vec<_> = std::iter
+            ::from_fn(|| commentary.next_if(|line| line.sequence == record.sequence))
+            .collect();
+
+        if record.event == MatchEvent::Start {
+            pending.push((record, commentary));
+            continue;
+        }
+
+        let same_minute = match frames.last() {
+            Some(LiveFrame::Minute { minute, added_minute, .. }) =>
+                matches!(record.event, MatchEvent::AddedTime { .. }) ||
+                    (*minute == record.minute && *added_minute == record.added_minute),
+            _ => false,
+        };
+        if !same_minute {
+            frames.push(LiveFrame::Minute {
+                minute: record.minute,
+                added_minute: record.added_minute,
+                events: Vec::new(),
+                commentary: Vec::new(),
+            });
+        }
+
+        if let Some(LiveFrame::Minute { events, commentary: lines, .. }) = frames.last_mut() {
+            for (record, commentary) in pending.drain(..).chain(std::iter::once((record, commentary))) {
+                events.push(record);
+                lines.extend(commentary);
+            }
+        }
+    }
+
+    frames.push(LiveFrame::Summary {
+        game_result: game.game_result.map(Box::new),
+        man_of_the_match: game.man_of_the_match,
+        seed: game.seed,
+    });
+
+    frames
+}
+
+/// Simulates the same match `simulations` times spread across all CPU cores.
+/// Game `i` is played with `seed + i`, so the whole batch is reproducible.
+pub fn simulate_batch(
+    teams: [Team; 2],
+    simulations: u32,
+    seed: Option<u64>
+) -> Result<BatchResult, String> {
+    let seed = seed.unwrap_or_else(generate_seed);
+    let team_names = [teams[0].name.clone(), teams[1].name.clone()];
+
+    let workers = thread::available_parallelism()
+        .map(|n| n.get() as u32)
+        .unwrap_or(1)
+        .clamp(1, simulations.max(1));
+
+    let partials: Vec<Result<BatchStats, String>> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|worker| {
+                let teams = &teams;
+                let team_names = &team_names;
+                scope.spawn(move || {
+                    let mut stats = BatchStats::default();
+                    let mut i = worker;
+                    while i < simulations {
+                        let game = Game::create_game_with_mode(
+                            teams.clone(),
+                            Some(seed.wrapping_add(i as u64)),
+                            MatchMode::League,
+                            false
+                        )?;
+                        stats.add_game(&game, team_names);
+                        i += workers;
+                    }
+                    Ok(stats)
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|h| h.join().unwrap_or_else(|_| Err("Simulation worker panicked".to_string())))
+            .collect()
+    });
+
+    let mut total = BatchStats::default();
+    for partial in partials {
+        total.merge(partial?);
+    }
+
+    Ok(total.into_result(seed, TOP_SCORERS))
 }
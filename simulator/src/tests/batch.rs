@@ -0,0 +1,100 @@
+use crate::handlers::game::simulate_batch::handle_simulate_batch;
+use crate::models::game::batch_result::BatchStats;
+use crate::models::game::game::Game;
+use crate::models::game::match_mode::MatchMode;
+use crate::tests::load_alpha_55;
+
+#[test]
+fn test_batch_simulation_distributions() {
+    let teams = load_alpha_55();
+
+    let simulations = 500;
+    let res = handle_simulate_batch(teams, simulations, Some(7)).expect("batch failed");
+
+    println!(
+        "Batch over {} simulations: win={}, draw={}, loss={}, mean={:?}, variance={:?}",
+        res.simulations,
+        res.win_probability,
+        res.draw_probability,
+        res.loss_probability,
+        res.goals_mean,
+        res.goals_variance
+    );
+
+    assert_eq!(res.simulations, simulations);
+    assert_eq!(res.seed, 7);
+
+    let total_probability = res.win_probability + res.draw_probability + res.loss_probability;
+    assert!((total_probability - 1.0).abs() < 0.001, "Outcome probabilities must add up to 1");
+
+    let histogram_games: u32 = res.scorelines
+        .iter()
+        .map(|s| s.count)
+        .sum();
+    assert_eq!(histogram_games, simulations, "Scoreline histogram must cover every game");
+
+    let histogram_mean: f32 =
+        res.scorelines
+            .iter()
+            .map(|s| (s.score[0] as f32) * (s.count as f32))
+            .sum::<f32>() / (simulations as f32);
+    assert!((histogram_mean - res.goals_mean[0]).abs() < 0.001, "Goal mean does not match histogram");
+
+    assert!(res.goals_variance[0] > 0.0, "Goals should vary between simulations");
+    assert!(!res.top_scorers.is_empty(), "No scorers in the whole batch");
+    assert!(
+        res.top_scorers.windows(2).all(|w| w[0].goals >= w[1].goals),
+        "Top scorers must be sorted by goals"
+    );
+}
+
+#[test]
+fn test_batch_simulation_same_seed_same_result() {
+    let teams = load_alpha_55();
+
+    let first = handle_simulate_batch(teams.clone(), 100, Some(99)).expect("batch failed");
+    let second = handle_simulate_batch(teams, 100, Some(99)).expect("batch failed");
+
+    assert_eq!(first.win_probability, second.win_probability);
+    assert_eq!(first.goals_mean, second.goals_mean);
+    assert_eq!(
+        serde_json::to_string(&first.scorelines).unwrap(),
+        serde_json::to_string(&second.scorelines).unwrap(),
+        "Same seed should give the same histogram regardless of the threads"
+    );
+}
+
+#[test]
+fn test_batch_simulation_rejects_invalid_requests() {
+    let teams = load_alpha_55();
+
+    assert!(handle_simulate_batch(teams.clone(), 0, None).is_err());
+    assert!(handle_simulate_batch(teams.clone(), 10001, None).is_err());
+
+    let mut short_bench = teams;
+    short_bench[1].bench_players.truncate(2);
+    let err = handle_simulate_batch(short_bench, 10, None).unwrap_err();
+    assert_eq!(err, "Team 2 must have at least 5 players on the bench");
+}
+
+#[test]
+fn test_batch_scorers_come_from_the_goal_events() {
+    let teams = load_alpha_55();
+    let team_names = [teams[0].name.clone(), teams[1].name.clone()];
+
+    let mut stats = BatchStats::default();
+    let mut goals = 0;
+    for seed in 0..20 {
+        let game = Game::create_game_with_mode(teams.clone(), Some(seed), MatchMode::League, false)
+            .expect("create_game failed");
+        assert!(game.logs.is_empty(), "Batch games don't need the legacy logs");
+
+        let score = game.game_result.as_ref().unwrap().score;
+        goals += (score[0] as u32) + (score[1] as u32);
+        stats.add_game(&game, &team_names);
+    }
+
+    assert!(goals > 0, "No goals in 20 games");
+    assert_eq!(stats.scorers.values().sum::<u32>(), goals, "Every goal must have a scorer");
+    assert!(stats.scorers.keys().all(|(team_name, _, _)| team_names.contains(team_name)));
+}
@@ -0,0 +1,123 @@
+use serde_json::from_str;
+use std::collections::HashMap;
+use std::fs;
+use crate::handlers::game::simulate_game::handle_simulate_game;
+use crate::models::game::commentary::{ Commentator, CommentaryTemplates };
+use crate::models::game::match_event::{ EventPlayer, EventRecord, GoalKind, MatchEvent };
+use crate::models::game::match_mode::MatchMode;
+use crate::tests::load_alpha_55;
+
+fn team_names() -> [String; 2] {
+    ["Home".to_string(), "Away".to_string()]
+}
+
+fn player(team: u8, index: u8) -> EventPlayer {
+    EventPlayer { team, index, name: format!("Player {}-{}", team, index), number: index + 1 }
+}
+
+fn goal(events: &mut Vec<EventRecord>, minute: u8, scorer: EventPlayer) {
+    EventRecord::push(events, minute, MatchEvent::Goal {
+        scorer,
+        assistant: None,
+        kind: GoalKind::Shot,
+        zone: None,
+        xg: None,
+    });
+}
+
+#[test]
+fn test_every_language_has_the_same_keys() {
+    let keys = |lang: &str| {
+        CommentaryTemplates::load(lang).expect("Error loading templates");
+
+        let file_str = fs::read_to_string(format!("src/data/commentary/{}.json", lang)).expect(
+            "Failed to read templates"
+        );
+        let templates: HashMap<String, Vec<String>> = from_str(&file_str).expect("Invalid templates");
+        let mut keys: Vec<String> = templates.into_keys().collect();
+        keys.sort();
+        keys
+    };
+
+    assert_eq!(keys("en"), keys("es"));
+}
+
+#[test]
+fn test_unknown_language() {
+    assert!(CommentaryTemplates::load("xx").is_err());
+    assert!(CommentaryTemplates::load("../../Cargo").is_err());
+}
+
+#[test]
+fn test_match_commentary() {
+    let teams = load_alpha_55();
+
+    for lang in ["en", "es"] {
+        for seed in 0..5 {
+            let game = handle_simulate_game(teams.clone(), Some(seed), MatchMode::League, false, Some(lang))
+                .expect("Error creating game");
+
+            // One line for every event and another one for every assist
+            let assisted = game.events
+                .iter()
+                .filter(|e| matches!(&e.event, MatchEvent::Goal { assistant: Some(_), .. }))
+                .count();
+            assert_eq!(game.commentary.len(), game.events.len() + assisted);
+
+            for line in &game.commentary {
+                assert!(!line.text.is_empty());
+                assert!(!line.text.contains('{'), "Placeholder left in: {}", line.text);
+            }
+            assert!(game.commentary.windows(2).all(|w| w[0].sequence <= w[1].sequence));
+
+            // Same seed, same words
+            let replay = handle_simulate_game(teams.clone(), Some(seed), MatchMode::League, false, Some(lang))
+                .expect("Error creating game");
+            assert_eq!(game.commentary, replay.commentary);
+        }
+    }
+
+    let game = handle_simulate_game(teams, Some(1), MatchMode::League, false, None).expect(
+        "Error creating game"
+    );
+    assert!(game.commentary.is_empty(), "No commentary without a language");
+}
+
+#[test]
+fn test_goal_context() {
+    let templates: CommentaryTemplates = from_str(
+        r#"{
+            "goal.shoot": ["goal {player} {score}"],
+            "goal.equaliser": ["equaliser {player} {score}"],
+            "goal.late_winner": ["late winner {player} {team} {score}"],
+            "goal.hat_trick": ["hat-trick {player} {score}"]
+        }"#
+    ).expect("Invalid templates");
+
+    let mut events = Vec::new();
+    EventRecord::push(&mut events, 0, MatchEvent::Start);
+    goal(&mut events, 10, player(0, 9));
+    goal(&mut events, 20, player(1, 4));
+    goal(&mut events, 30, player(0, 9));
+    goal(&mut events, 60, player(0, 9));
+    goal(&mut events, 70, player(1, 4));
+    goal(&mut events, 80, player(1, 2));
+    goal(&mut events, 89, player(1, 2));
+
+    // Events without templates are not commented
+    let lines: Vec<String> = Commentator::new(templates, team_names(), 7)
+        .comment_all(&events)
+        .into_iter()
+        .map(|l| l.text)
+        .collect();
+
+    assert_eq!(lines, [
+        "goal Player 0-9 1-0",
+        "equaliser Player 1-4 1-1",
+        "goal Player 0-9 2-1",
+        "hat-trick Player 0-9 3-1",
+        "goal Player 1-4 3-2",
+        "equaliser Player 1-2 3-3",
+        "late winner Player 1-2 Away 3-4",
+    ]);
+}
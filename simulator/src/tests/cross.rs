@@ -24,10 +24,13 @@ mod tests {
     use crate::models::player::status::Status;
     use crate::models::player::instructions::Instructions;
     use crate::models::player::stats::Stats;
+    use crate::models::player::match_stats::PlayerMatchStats;
     use crate::models::game::team::Team;
+    use crate::models::game::tactics::Tactics;
     use crate::models::game::game_result::GameResult;
-    use crate::models::game::log::Log;
+    use crate::models::game::match_event::EventRecord;
     use crate::models::player::actions::Actions;
+    use crate::utils::generate_random_number::{ create_game_rng, generate_seed };
 
     // Helper to create a dummy player
     fn create_dummy_player(number: u8, position: Position, country: Country) -> Player {
@@ -45,6 +48,8 @@ mod tests {
             status: Status::generate_default_status(),
             instructions: Instructions::generate_empty_instructions(),
             stats: Stats::generate_default_status(),
+            energy: 100.0,
+            match_stats: PlayerMatchStats::default(),
         }
     }
 
@@ -70,14 +75,21 @@ mod tests {
             aura: ["NONE".to_string(), "NONE".to_string(), "NONE".to_string()],
             bench_players: vec![],
             player_name: "Dummy".to_string(),
+            tactics: Tactics::default(),
+            substitutions: vec![],
+            manager_rules: vec![],
+            substitutions_made: vec![],
+            substitution_windows: vec![],
+            sent_off_players: vec![],
+            injured_players: vec![],
         }
     }
 
     // Create a test game
-    fn create_test_game() -> ([Team; 2], GameResult, Vec<Log>, [u8; 2], [u8; 2]) {
+    fn create_test_game() -> ([Team; 2], GameResult, Vec<EventRecord>, [u8; 2], [u8; 2]) {
         let teams = [create_dummy_team(), create_dummy_team()];
-        let game_result = GameResult { score: [0, 0] };
-        let logs: Vec<Log> = vec![];
+        let game_result = GameResult::create();
+        let logs: Vec<EventRecord> = vec![];
         let ball_possession = [0, 10]; // striker will shoot
         let last_pass_player = [0, 9]; // dummy last passer
 
@@ -91,42 +103,62 @@ mod tests {
 
         let attempts = 1000;
 
+        let mut rng = create_game_rng(generate_seed());
+
         for _ in 0..attempts {
-            let (mut teams, _game_result, mut logs, mut ball_possession, mut last_pass_player) =
+            let (mut teams, mut game_result, mut logs, mut ball_possession, mut last_pass_player) =
                 create_test_game();
 
-            let starting_team = ball_possession[0];
-            let starting_player = ball_possession[1];
-
-            let result = Actions::cross(&mut teams, &mut ball_possession, &mut logs, 10);
+            let result = Actions::cross(
+                &mut teams,
+                &mut ball_possession,
+                &mut last_pass_player,
+                &mut logs,
+                10,
+                &mut game_result,
+                &mut rng
+            );
 
-            // cross() always returns bool
-            assert!(result == true || result == false);
+            // The cross is always followed by what happened to the ball
+            let follow_up = logs[1].description();
+            let follow_up = follow_up.as_str();
+            match logs[0].description().as_str() {
+                "success.cross" => {
+                    successes += 1;
+                    assert!(result);
+                    assert!(
+                        matches!(
+                            follow_up,
+                            "header_down.cross" |
+                                "goal.header" |
+                                "caught.header" |
+                                "parried.header" |
+                                "corner.header" |
+                                "fumbled.header" |
+                                "failed.header" |
+                                "cleared.cross" |
+                                "claimed.cross"
+                        ),
+                        "Unexpected follow up of a good cross: {}",
+                        follow_up
+                    );
+                }
+                "failed.cross" => {
+                    failures += 1;
+                    assert!(!result);
+                    assert!(
+                        matches!(follow_up, "intercepted.cross" | "goal_kick.cross" | "corner.cross"),
+                        "Unexpected follow up of a bad cross: {}",
+                        follow_up
+                    );
 
-            // Count logs
-            for log in &logs {
-                match log.description.as_str() {
-                    "success.cross" => {
-                        successes += 1;
-                    }
-                    "failed.cross" => {
-                        failures += 1;
+                    // Only a corner keeps the ball in the attacking team
+                    if follow_up != "corner.cross" {
+                        assert_eq!(ball_possession[0], 1, "A bad cross must lose the ball");
                     }
-                    _ => {}
                 }
+                other => panic!("Unexpected cross log: {}", other),
             }
-
-            // crossing does NOT change possession
-            assert_eq!(
-                ball_possession[0],
-                starting_team,
-                "Cross changed team possession unexpectedly!"
-            );
-            assert_eq!(
-                ball_possession[1],
-                starting_player,
-                "Cross changed player possession unexpectedly!"
-            );
         }
 
         println!(
@@ -144,4 +176,94 @@ mod tests {
         assert!(successes > 600, "Cross success rate too hight (expected > 60%)");
         assert!(failures > 150, "Cross failure rate too low (expected > 15%)");
     }
+
+    #[test]
+    fn test_tall_attackers_win_aerial_duels() {
+        let mut rng = create_game_rng(20);
+
+        let mut headers_won = |attacker_height: u8| {
+            let mut won = 0;
+            for _ in 0..1000 {
+                let (mut teams, mut game_result, mut logs, mut ball_possession, mut last_pass_player) =
+                    create_test_game();
+                teams[0].players[10].height_cm = attacker_height;
+
+                Actions::cross(
+                    &mut teams,
+                    &mut ball_possession,
+                    &mut last_pass_player,
+                    &mut logs,
+                    10,
+                    &mut game_result,
+                    &mut rng
+                );
+
+                if logs.iter().any(|l| l.description().ends_with(".header") || l.description() == "header_down.cross") {
+                    won += 1;
+                }
+            }
+            won
+        };
+
+        let tall = headers_won(200);
+        let short = headers_won(165);
+
+        assert!(tall > short, "Tall strikers must win more headers ({} vs {})", tall, short);
+    }
+
+    /// Receivers of the knock-downs of many crosses by `crosser`
+    fn knock_down_receivers(teams: &[Team; 2], crosser: u8) -> Vec<usize> {
+        let mut rng = create_game_rng(31);
+        let mut receivers = Vec::new();
+
+        for _ in 0..2000 {
+            let (_, mut game_result, mut logs, _, mut last_pass_player) = create_test_game();
+            let mut teams = teams.clone();
+            let mut ball_possession = [0, crosser];
+
+            Actions::cross(
+                &mut teams,
+                &mut ball_possession,
+                &mut last_pass_player,
+                &mut logs,
+                10,
+                &mut game_result,
+                &mut rng
+            );
+
+            if logs.iter().any(|l| l.description() == "header_down.cross") {
+                assert_eq!(ball_possession[0], 0, "A knock-down stays in the attacking team");
+                receivers.push(ball_possession[1] as usize);
+            }
+        }
+
+        assert!(!receivers.is_empty(), "No knock-downs in 2000 crosses");
+        receivers
+    }
+
+    #[test]
+    fn test_knock_downs_go_to_teammates_in_the_box() {
+        let (teams, ..) = create_test_game();
+
+        for receiver in knock_down_receivers(&teams, 10) {
+            assert!(
+                [6, 9, 10].contains(&receiver),
+                "Knocked down to {:?}",
+                teams[0].players[receiver].current_position
+            );
+        }
+
+        // With only the header in the box the ball goes to the nearest line, the wide midfielders
+        let (mut teams, ..) = create_test_game();
+        teams[0].players[6].current_position = Position::Defender;
+        teams[0].players[10].current_position = Position::Defender;
+
+        for receiver in knock_down_receivers(&teams, 7) {
+            assert!(
+                [7, 8].contains(&receiver),
+                "Knocked down to {:?}",
+                teams[0].players[receiver].current_position
+            );
+        }
+    }
 }
@@ -0,0 +1,75 @@
+use crate::handlers::game::simulate_batch::handle_simulate_batch;
+use crate::models::game::team::Team;
+use crate::tests::load_alpha_55;
+use crate::models::player::instructions::DefensiveInstruction;
+
+/// Both teams defend with the same instruction
+fn with_instruction(instruction: DefensiveInstruction) -> [Team; 2] {
+    let mut teams = load_alpha_55();
+    for team in teams.iter_mut() {
+        for player in team.players.iter_mut().chain(team.bench_players.iter_mut()) {
+            player.instructions.defensive = vec![instruction.clone()];
+        }
+    }
+    teams
+}
+
+#[test]
+fn test_defensive_instructions_change_batch_stats() {
+    let simulations = 300;
+
+    let destroy = handle_simulate_batch(
+        with_instruction(DefensiveInstruction::Destroy),
+        simulations,
+        Some(14)
+    ).expect("batch failed");
+    let normal = handle_simulate_batch(
+        with_instruction(DefensiveInstruction::Normal),
+        simulations,
+        Some(14)
+    ).expect("batch failed");
+    let passive = handle_simulate_batch(
+        with_instruction(DefensiveInstruction::Passive),
+        simulations,
+        Some(14)
+    ).expect("batch failed");
+
+    for (name, res) in [("Destroy", &destroy), ("Normal", &normal), ("Passive", &passive)] {
+        println!(
+            "{}: fouls={:?}, yellow={:?}, red={:?}, tackles={:?}",
+            name,
+            res.fouls_mean,
+            res.yellow_cards_mean,
+            res.red_cards_mean,
+            res.tackles_mean
+        );
+    }
+
+    for i in 0..2 {
+        assert!(destroy.tackles_mean[i] > normal.tackles_mean[i], "Destroy must win more tackles");
+        assert!(normal.tackles_mean[i] > passive.tackles_mean[i], "Passive must win less tackles");
+        assert!(destroy.fouls_mean[i] > normal.fouls_mean[i], "Destroy must foul more");
+        assert!(normal.fouls_mean[i] > passive.fouls_mean[i], "Passive must foul less");
+        assert!(
+            destroy.yellow_cards_mean[i] > passive.yellow_cards_mean[i],
+            "Destroy must be booked more than Passive"
+        );
+    }
+}
+
+#[test]
+fn test_offside_trap_keeps_the_defensive_style() {
+    let mut teams = load_alpha_55();
+    let instructions = &mut teams[0].players[1].instructions;
+
+    instructions.defensive = vec![DefensiveInstruction::Offside, DefensiveInstruction::Destroy];
+    assert!(instructions.plays_offside_trap());
+    assert_eq!(instructions.defensive_style(), DefensiveInstruction::Destroy);
+
+    instructions.defensive = vec![DefensiveInstruction::Offside];
+    assert!(instructions.plays_offside_trap());
+    assert_eq!(instructions.defensive_style(), DefensiveInstruction::Normal);
+
+    instructions.defensive = vec![];
+    assert!(!instructions.plays_offside_trap());
+}
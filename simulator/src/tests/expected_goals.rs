@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use crate::models::game::game::Game;
+use crate::models::game::match_event::{ GoalKind, MatchEvent };
+use crate::tests::load_alpha_55;
+use crate::models::player::position::Position;
+
+#[test]
+fn test_xg_is_credited_to_teams_and_players() {
+    let teams = load_alpha_55();
+    let mut xg = 0.0;
+    let mut open_play_goals = 0;
+
+    for seed in 0..100 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+        let game_result = res.game_result.unwrap();
+
+        for i in 0..2 {
+            let stats = &game_result.teams_stats[i];
+            let players_xg: f32 = game_result.players_stats[i].iter().map(|l| l.xg).sum();
+
+            assert!((stats.xg - players_xg).abs() < 0.001, "Team xG must add up the players xG");
+            assert_eq!(stats.shoots == 0, stats.xg == 0.0, "Only shots add xG");
+            xg += stats.xg;
+        }
+
+        open_play_goals += res.logs
+            .iter()
+            .filter(|l| l.description == "goal.shoot" || l.description == "goal.header")
+            .count();
+    }
+
+    println!("Over 100 games: xG={}, open play goals={}", xg, open_play_goals);
+
+    // Goalkeepers make the difference, but xG must follow the goals
+    let ratio = (open_play_goals as f32) / xg;
+    assert!(ratio > 0.5 && ratio < 2.0, "Goals and xG are too far apart ({})", ratio);
+}
+
+#[test]
+fn test_goals_are_shots_on_target_of_the_scorer() {
+    let teams = load_alpha_55();
+    let mut goals = 0;
+
+    for seed in 0..100 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+        let game_result = res.game_result.unwrap();
+
+        for i in 0..2 {
+            let players_on_target: i32 = game_result.players_stats[i].iter().map(|l| l.shoots_suc).sum();
+            assert_eq!(
+                game_result.teams_stats[i].shoots_suc,
+                players_on_target,
+                "Team shots on target must add up the players shots on target"
+            );
+        }
+
+        // Open play goals of every scorer by team and shirt number
+        let mut scorers: HashMap<(u8, u8), i32> = HashMap::new();
+        for event in &res.events {
+            if let MatchEvent::Goal { scorer, kind: GoalKind::Shot | GoalKind::Header, .. } = &event.event {
+                *scorers.entry((scorer.team, scorer.number)).or_insert(0) += 1;
+                goals += 1;
+            }
+        }
+
+        for ((team, number), scored) in scorers {
+            let line = game_result.players_stats[team as usize]
+                .iter()
+                .find(|l| l.player_number == number)
+                .expect("The scorer has a stat line");
+            assert!(line.shoots_suc >= scored, "Every goal is a shot on target of the scorer");
+        }
+    }
+
+    assert!(goals > 0, "No open play goals in 100 games");
+}
+
+#[test]
+fn test_poachers_get_more_xg_in_the_box() {
+    let mut teams = load_alpha_55();
+    let striker = teams[0].players
+        .iter()
+        .position(|p| p.position == Position::Striker)
+        .expect("Fixture has no striker");
+
+    let mut striker_xg = |finishing: u8| {
+        teams[0].players[striker].skills.finishing = finishing;
+        (0..30)
+            .map(|seed| {
+                let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+                let game_result = res.game_result.unwrap();
+                let number = teams[0].players[striker].number;
+                let line = game_result.players_stats[0]
+                    .iter()
+                    .find(|l| l.player_number == number)
+                    .expect("Striker has no stat line");
+                line.xg / (line.shoots.max(1) as f32)
+            })
+            .sum::<f32>()
+    };
+
+    assert!(striker_xg(99) > striker_xg(20), "Finishing must raise the xG of a striker");
+}
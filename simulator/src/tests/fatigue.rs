@@ -0,0 +1,85 @@
+use crate::models::game::game::Game;
+use crate::tests::{ load_alpha_55, load_teams };
+
+fn average_starters_energy(game_energy: &[crate::models::game::game_result::PlayerEnergy]) -> f32 {
+    // Starters without the goalkeeper, bench players are listed after the 11 starters
+    let outfield = &game_energy[1..11];
+    outfield.iter().map(|p| p.energy).sum::<f32>() / (outfield.len() as f32)
+}
+
+#[test]
+fn test_players_get_tired_during_the_match() {
+    let mut team99 = load_teams("team-alpha-99.json")[0].clone();
+    let mut team55 = load_alpha_55()[0].clone();
+    team99.substitutions.clear();
+    team55.substitutions.clear();
+
+    // First match where both squads finish unchanged, without injuries or red cards
+    let game_result = (0..50)
+        .map(|seed| {
+            Game::create_game([team99.clone(), team55.clone()], Some(seed))
+                .expect("create_game failed")
+                .game_result.unwrap()
+        })
+        .find(|r| r.injuries.is_empty() && r.bookings.iter().all(|b| !b.card.is_sending_off()))
+        .expect("Every match had injuries or red cards");
+
+    for team_energy in &game_result.energy {
+        assert_eq!(team_energy.len(), 22, "Energy must be returned for the whole squad");
+
+        for starter in &team_energy[..11] {
+            assert!(starter.energy < 100.0, "{} didn't get tired", starter.player_name);
+            assert!(starter.energy > 0.0, "{} is completely exhausted", starter.player_name);
+        }
+
+        for bench in &team_energy[11..] {
+            assert_eq!(bench.energy, 100.0, "Unused substitutes must stay fresh");
+        }
+    }
+
+    let energy99 = average_starters_energy(&game_result.energy[0]);
+    let energy55 = average_starters_energy(&game_result.energy[1]);
+
+    println!("Average energy at the end: team99 = {}, team55 = {}", energy99, energy55);
+
+    assert!(energy99 > energy55, "Higher stamina must slow the drain");
+}
+
+#[test]
+fn test_endless_engine_slows_the_drain() {
+    let mut base = load_alpha_55()[0].clone();
+    base.substitutions.clear();
+
+    let mut engine = base.clone();
+    engine.aura = ["EndlessEngine".to_string(), "None".to_string(), "None".to_string()];
+
+    let mut plain_total = 0.0;
+    let mut engine_total = 0.0;
+
+    for seed in 0..20 {
+        let plain = Game::create_game([base.clone(), base.clone()], Some(seed)).unwrap();
+        let boosted = Game::create_game([engine.clone(), base.clone()], Some(seed)).unwrap();
+
+        plain_total += average_starters_energy(&plain.game_result.unwrap().energy[0]);
+        engine_total += average_starters_energy(&boosted.game_result.unwrap().energy[0]);
+    }
+
+    assert!(engine_total > plain_total, "EndlessEngine must keep the team fresher");
+}
+
+#[test]
+fn test_tired_players_carry_energy_into_the_match() {
+    let mut team = load_alpha_55()[0].clone();
+    team.substitutions.clear();
+
+    let mut tired = team.clone();
+    for player in &mut tired.players {
+        player.energy = 40.0;
+    }
+
+    let res = Game::create_game([tired, team], Some(11)).unwrap();
+    let game_result = res.game_result.unwrap();
+
+    assert!(average_starters_energy(&game_result.energy[0]) < 40.0);
+    assert!(average_starters_energy(&game_result.energy[1]) > 40.0);
+}
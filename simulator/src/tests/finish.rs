@@ -24,10 +24,14 @@ mod tests {
     use crate::models::player::status::Status;
     use crate::models::player::instructions::Instructions;
     use crate::models::player::stats::Stats;
+    use crate::models::player::match_stats::PlayerMatchStats;
     use crate::models::game::team::Team;
+    use crate::models::game::shot_zone::ShotZone;
+    use crate::models::game::tactics::Tactics;
     use crate::models::game::game_result::GameResult;
-    use crate::models::game::log::Log;
+    use crate::models::game::match_event::EventRecord;
     use crate::models::player::actions::Actions;
+    use crate::utils::generate_random_number::{ create_game_rng, generate_seed };
 
     // Helper to create a dummy player
     fn create_dummy_player(number: u8, position: Position, country: Country) -> Player {
@@ -45,6 +49,8 @@ mod tests {
             status: Status::generate_default_status(),
             instructions: Instructions::generate_empty_instructions(),
             stats: Stats::generate_default_status(),
+            energy: 100.0,
+            match_stats: PlayerMatchStats::default(),
         }
     }
 
@@ -70,14 +76,21 @@ mod tests {
             aura: ["NONE".to_string(), "NONE".to_string(), "NONE".to_string()],
             bench_players: vec![],
             player_name: "Dummy".to_string(),
+            tactics: Tactics::default(),
+            substitutions: vec![],
+            manager_rules: vec![],
+            substitutions_made: vec![],
+            substitution_windows: vec![],
+            sent_off_players: vec![],
+            injured_players: vec![],
         }
     }
 
     // Create a test game
-    fn create_test_game() -> ([Team; 2], GameResult, Vec<Log>, [u8; 2], [u8; 2]) {
+    fn create_test_game() -> ([Team; 2], GameResult, Vec<EventRecord>, [u8; 2], [u8; 2]) {
         let teams = [create_dummy_team(), create_dummy_team()];
-        let game_result = GameResult { score: [0, 0] };
-        let logs: Vec<Log> = vec![];
+        let game_result = GameResult::create();
+        let logs: Vec<EventRecord> = vec![];
         let ball_possession = [0, 10]; // striker will shoot
         let last_pass_player = [0, 9]; // dummy last passer
 
@@ -91,21 +104,19 @@ mod tests {
 
         let attempts = 1000;
 
+        let mut rng = create_game_rng(generate_seed());
+
         for _ in 0..attempts {
-            let (mut teams, _game_result, _logs, mut ball_possession, _last_pass_player) =
-                create_test_game();
+            let (teams, _game_result, _logs, ball_possession, _last_pass_player) = create_test_game();
 
-            let result = Actions::finish(&mut teams, &mut ball_possession);
+            let (xg, scored) = Actions::finish(&teams, &ball_possession, &ShotZone::InsideBox, &mut rng);
+            assert!(xg > 0.0 && xg < 1.0, "xG out of range: {}", xg);
 
-            if result {
+            if scored {
                 goals += 1;
             } else {
                 misses += 1;
             }
-
-            // Ensure the shooter is the current ball holder
-            let shooter = &teams[ball_possession[0] as usize].players[ball_possession[1] as usize];
-            assert!(shooter.skills.finishing >= 0, "Shooter skill invalid");
         }
 
         println!("Finish results over {} attempts: goals={}, misses={}", attempts, goals, misses);
@@ -114,10 +125,23 @@ mod tests {
         assert!(goals > 0, "No goals recorded in 1000 attempts");
         assert!(misses > 0, "No misses recorded in 1000 attempts");
 
-        //? this is function is not using for the moment, I just keep it for future but probably haven't use.
+        // Rough probability sanity checks, most shots miss
+        assert!(goals < 400, "Goal probability unreasonably high (>40%)");
+        assert!(misses > 500, "Miss probability unreasonably low (<50%)");
+    }
+
+    #[test]
+    fn test_finish_depends_on_the_zone() {
+        let mut rng = create_game_rng(21);
+        let (teams, _game_result, _logs, ball_possession, _last_pass_player) = create_test_game();
+
+        let mut goals = |zone: ShotZone| {
+            (0..5000).filter(|_| Actions::finish(&teams, &ball_possession, &zone, &mut rng).1).count()
+        };
+
+        let long_range = goals(ShotZone::LongRange);
+        let one_on_one = goals(ShotZone::OneOnOne);
 
-        // Rough probability sanity checks
-        assert!(goals < 400, "Goal probability unreasonably low (<40%)");
-        assert!(misses > 500, "Miss probability unreasonably low (<500%)");
+        assert!(one_on_one > long_range, "One-on-ones must score more than long shots");
     }
 }
@@ -0,0 +1,115 @@
+use crate::models::game::booking::BookingType;
+use crate::models::game::game::Game;
+use crate::tests::load_alpha_55;
+use crate::models::player::position::Position;
+
+#[test]
+fn test_fouls_and_cards_during_open_play() {
+    let teams = load_alpha_55();
+
+    let games = 300;
+    let mut fouls = 0;
+    let mut yellows = 0;
+    let mut second_yellows = 0;
+    let mut reds = 0;
+
+    for seed in 0..games {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+        let game_result = res.game_result.unwrap();
+
+        fouls += res.logs
+            .iter()
+            .filter(|l| l.description == "foul.dribble")
+            .count();
+
+        for booking in &game_result.bookings {
+            match booking.card {
+                BookingType::Yellow => {
+                    yellows += 1;
+                }
+                BookingType::SecondYellow => {
+                    second_yellows += 1;
+                }
+                BookingType::Red => {
+                    reds += 1;
+                }
+            }
+
+            // Every card must also be in the logs
+            assert!(
+                res.logs
+                    .iter()
+                    .any(|l| {
+                        l.minute == booking.minute &&
+                            l.player_name == booking.player_name &&
+                            l.description == booking.card.log_description()
+                    }),
+                "Booking without log"
+            );
+        }
+
+        // A second yellow always needs a previous yellow
+        for booking in game_result.bookings.iter().filter(|b| b.card == BookingType::SecondYellow) {
+            assert!(
+                game_result.bookings
+                    .iter()
+                    .any(|b| {
+                        b.card == BookingType::Yellow &&
+                            b.team_index == booking.team_index &&
+                            b.player_name == booking.player_name &&
+                            b.player_number == booking.player_number
+                    }),
+                "Second yellow without a first yellow"
+            );
+        }
+    }
+
+    println!(
+        "Over {} games: fouls = {}, yellows = {}, second yellows = {}, reds = {}",
+        games,
+        fouls,
+        yellows,
+        second_yellows,
+        reds
+    );
+
+    assert!(fouls > 0, "No fouls in {} games", games);
+    assert!(yellows > 0, "No yellow cards in {} games", games);
+    assert!(reds + second_yellows > 0, "Nobody was sent off in {} games", games);
+    assert!(yellows > reds, "Straight reds must be rarer than yellows");
+}
+
+#[test]
+fn test_sent_off_player_leaves_the_pitch() {
+    let mut team = load_alpha_55()[0].clone();
+    team.substitutions = vec![
+        crate::models::game::substitution::Substitution { minute: 60, player_out: 3, player_in: 3 },
+        crate::models::game::substitution::Substitution { minute: 60, player_out: 8, player_in: 8 }
+    ];
+
+    let sent_off = team.players[3].name.clone();
+    let moved = team.players[8].name.clone();
+
+    team.send_off(3);
+
+    assert_eq!(team.players.len(), 10);
+    assert!(team.players.iter().all(|p| p.name != sent_off));
+    assert_eq!(team.sent_off_players.len(), 1);
+
+    // The substitution of the sent off player is dropped, the other one follows its player
+    assert_eq!(team.substitutions.len(), 1);
+    assert_eq!(team.players[team.substitutions[0].player_out as usize].name, moved);
+}
+
+#[test]
+fn test_sent_off_goalkeeper_is_replaced() {
+    let mut team = load_alpha_55()[0].clone();
+
+    team.send_off(0);
+
+    let goalkeepers = team.players
+        .iter()
+        .filter(|p| p.position == Position::Goalkeeper)
+        .count();
+    assert_eq!(goalkeepers, 1, "An outfield player must take the gloves");
+}
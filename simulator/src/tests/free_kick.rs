@@ -0,0 +1,150 @@
+use crate::models::game::free_kick::FreeKickZone;
+use crate::models::game::game::Game;
+use crate::models::game::set_piece::SetPiece;
+use crate::models::game::game_result::GameResult;
+use crate::models::game::match_event::{ GoalKind, MatchEvent };
+use crate::tests::load_alpha_55;
+use crate::models::player::actions::Actions;
+use crate::models::player::position::Position;
+use crate::utils::generate_random_number::create_game_rng;
+
+#[test]
+fn test_free_kick_zone_from_position() {
+    assert_eq!(FreeKickZone::from_position(&Position::Striker), FreeKickZone::Direct);
+    assert_eq!(FreeKickZone::from_position(&Position::Left_Wing), FreeKickZone::Cross);
+    assert_eq!(FreeKickZone::from_position(&Position::Defender), FreeKickZone::Short);
+}
+
+#[test]
+fn test_designated_free_kick_taker() {
+    let mut team = load_alpha_55()[0].clone();
+
+    // Without designation the best striker of the ball takes it
+    for player in team.players.iter_mut() {
+        player.instructions.free_kick_kicker = 0;
+    }
+    team.players[6].skills.kicking = 99;
+    team.players[6].skills.shooting = 99;
+    assert_eq!(team.set_piece_taker(SetPiece::FreeKick), 6);
+
+    // The first choice takes it even if someone else kicks better
+    team.players[9].instructions.free_kick_kicker = 2;
+    team.players[4].instructions.free_kick_kicker = 1;
+    assert_eq!(team.set_piece_taker(SetPiece::FreeKick), 4);
+}
+
+#[test]
+fn test_direct_free_kick_outcomes() {
+    let teams = load_alpha_55();
+
+    let mut goals = 0;
+    for seed in 0..500 {
+        let mut teams = teams.clone();
+        let mut ball_possession = [0, 9];
+        let mut last_pass_player = [0, 8];
+        let mut logs = Vec::new();
+        let mut game_result = GameResult::create();
+        let mut rng = create_game_rng(seed);
+
+        let taker = teams[0].set_piece_taker(SetPiece::FreeKick);
+
+        let scored = Actions::free_kick(
+            &mut teams,
+            &mut ball_possession,
+            &mut last_pass_player,
+            &mut logs,
+            30,
+            &mut game_result,
+            FreeKickZone::Direct,
+            &mut rng
+        );
+
+        let first = &logs[0];
+        assert_eq!(first.event.subject().unwrap().name, teams[0].players[taker].name);
+        assert!(
+            ["wall.free_kick", "failed.free_kick", "saved.free_kick", "goal.free_kick"].contains(
+                &first.description().as_str()
+            ),
+            "Unexpected event {}",
+            first.description()
+        );
+
+        // The free kick is a shot of the taker, on target when saved or scored
+        let on_target = ["saved.free_kick", "goal.free_kick"].contains(&first.description().as_str()) as i32;
+        assert_eq!(game_result.teams_stats[0].shoots, 1);
+        assert_eq!(game_result.teams_stats[0].shoots_suc, on_target);
+        assert_eq!(teams[0].players[taker].match_stats.shoots, 1);
+        assert_eq!(teams[0].players[taker].match_stats.shoots_suc, on_target);
+
+        if scored {
+            goals += 1;
+            assert_eq!(game_result.score, [1, 0]);
+            if first.description() == "goal.free_kick" {
+                assert_eq!(ball_possession[0], 1, "The conceding team restarts");
+            }
+        } else {
+            assert_eq!(game_result.score[0], 0);
+        }
+    }
+
+    assert!(goals > 0, "No direct free kick goals in 500 tries");
+    assert!(goals < 250, "Too many direct free kick goals: {}", goals);
+}
+
+#[test]
+fn test_crossed_free_kick_goes_to_the_air() {
+    let teams = load_alpha_55();
+
+    let mut headers = 0;
+    for seed in 0..500 {
+        let mut teams = teams.clone();
+        let mut ball_possession = [0, 2];
+        let mut last_pass_player = [255, 255];
+        let mut logs = Vec::new();
+        let mut game_result = GameResult::create();
+        let mut rng = create_game_rng(seed);
+
+        let taker = teams[0].set_piece_taker(SetPiece::FreeKick);
+
+        Actions::free_kick(
+            &mut teams,
+            &mut ball_possession,
+            &mut last_pass_player,
+            &mut logs,
+            30,
+            &mut game_result,
+            FreeKickZone::Cross,
+            &mut rng
+        );
+
+        for record in &logs {
+            let MatchEvent::Goal { scorer, assistant, kind, .. } = &record.event else {
+                continue;
+            };
+            assert_ne!(*kind, GoalKind::FreeKick, "A crossed free kick doesn't go in directly");
+            if *kind == GoalKind::Header {
+                headers += 1;
+                assert_ne!(scorer.index as usize, taker, "The taker can't head their own delivery");
+                assert_eq!(assistant.as_ref().map(|a| a.index as usize), Some(taker));
+            }
+        }
+    }
+
+    assert!(headers > 0, "No headed goals from 500 crossed free kicks");
+}
+
+#[test]
+fn test_free_kicks_in_full_games() {
+    let teams = load_alpha_55();
+
+    let mut free_kicks = 0;
+    for seed in 0..100 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+        free_kicks += res.logs
+            .iter()
+            .filter(|l| l.description.ends_with(".free_kick"))
+            .count();
+    }
+
+    assert!(free_kicks > 0, "No free kicks in 100 games");
+}
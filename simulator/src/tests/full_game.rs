@@ -22,7 +22,7 @@ fn test_full_game_simulation_alpha_99() {
     let mut goals_total = [0u16, 0u16];
 
     for _ in 0..1000 {
-        let res = Game::create_game(teams.clone()).expect("create_game failed");
+        let res = Game::create_game(teams.clone(), None).expect("create_game failed");
         if let Some(game_result) = res.game_result {
             goals_total[0] += game_result.score[0] as u16;
             goals_total[1] += game_result.score[1] as u16;
@@ -44,18 +44,12 @@ fn test_full_game_simulation_alpha_99() {
 
 #[test]
 fn test_full_game_simulation_alpha_55() {
-    let file_str = fs
-        ::read_to_string("src/tests/fixtures/team-alpha-55.json")
-        .expect("Failed to read team-alpha-55.json");
-
-    let wrapper: TeamsWrapper = from_str(&file_str).expect("JSON deserialization failed");
-
-    let teams = wrapper.teams;
+    let teams = crate::tests::load_alpha_55();
 
     let mut goals_total = [0u16, 0u16];
 
     for _ in 0..1000 {
-        let res = Game::create_game(teams.clone()).expect("create_game failed");
+        let res = Game::create_game(teams.clone(), None).expect("create_game failed");
         if let Some(game_result) = res.game_result {
             goals_total[0] += game_result.score[0] as u16;
             goals_total[1] += game_result.score[1] as u16;
@@ -93,11 +87,7 @@ fn test_full_game_simulation_alpha_99_vs_55() {
     let team99 = wrapper99.teams[0].clone(); // pick first team
 
     // Load team 55
-    let team55_str = fs::read_to_string("src/tests/fixtures/team-alpha-55.json")
-        .expect("Failed to read team-alpha-55.json");
-    let wrapper55: TeamsWrapper = from_str(&team55_str)
-        .expect("JSON deserialization failed for team 55");
-    let team55 = wrapper55.teams[0].clone(); // pick first team
+    let team55 = crate::tests::load_alpha_55()[0].clone(); // pick first team
 
     let teams = [team99, team55];
 
@@ -105,7 +95,7 @@ fn test_full_game_simulation_alpha_99_vs_55() {
     let mut team55_wins = 0;
 
     for _ in 0..1000 {
-        let res = Game::create_game(teams.clone()).expect("create_game failed");
+        let res = Game::create_game(teams.clone(), None).expect("create_game failed");
 
         if let Some(game_result) = res.game_result {
             goals_total[0] += game_result.score[0] as u16;
@@ -162,7 +152,7 @@ fn test_full_game_simulation_draws_and_wins_team_99_vs_99() {
     let mut losses = 0;
 
     for _ in 0..1000 {
-        let res = Game::create_game(teams.clone()).expect("create_game failed");
+        let res = Game::create_game(teams.clone(), None).expect("create_game failed");
 
         if let Some(game_result) = res.game_result {
             if game_result.score[0] > game_result.score[1] {
@@ -190,3 +180,35 @@ fn test_full_game_simulation_draws_and_wins_team_99_vs_99() {
 }
 
 
+
+#[test]
+fn test_full_game_simulation_same_seed_same_result() {
+    let teams = crate::tests::load_alpha_55();
+
+    for seed in [0u64, 42, 1234567890] {
+        let first = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+        let second = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+
+        assert_eq!(first.seed, seed, "Seed must be echoed back in the result");
+        assert_eq!(
+            first.game_result.unwrap().score,
+            second.game_result.unwrap().score,
+            "Same seed should give the same score"
+        );
+        assert_eq!(
+            serde_json::to_string(&first.logs).unwrap(),
+            serde_json::to_string(&second.logs).unwrap(),
+            "Same seed should give the same logs"
+        );
+    }
+
+    // Without a seed a random one is generated and returned
+    let res = Game::create_game(teams.clone(), None).expect("create_game failed");
+    let replay = Game::create_game(teams.clone(), Some(res.seed)).expect("create_game failed");
+
+    assert_eq!(
+        serde_json::to_string(&res.logs).unwrap(),
+        serde_json::to_string(&replay.logs).unwrap(),
+        "Replaying the returned seed should reproduce the match"
+    );
+}
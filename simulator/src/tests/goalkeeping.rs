@@ -0,0 +1,135 @@
+use crate::models::game::game::Game;
+use crate::models::game::log::Log;
+use crate::models::game::tactics::{ Pressing, Tempo };
+use crate::models::game::team::Team;
+use crate::tests::load_alpha_55;
+use crate::models::player::position::Position;
+
+fn goalkeeper_index(team: &Team) -> usize {
+    team.players
+        .iter()
+        .position(|p| p.position == Position::Goalkeeper)
+        .expect("Fixture has no goalkeeper")
+}
+
+fn count(logs: &[Log], descriptions: &[&str]) -> usize {
+    logs.iter()
+        .filter(|l| descriptions.contains(&l.description.as_str()))
+        .count()
+}
+
+#[test]
+fn test_saves_claims_and_clean_sheets_are_reported() {
+    let teams = load_alpha_55();
+    let mut clean_sheets = 0;
+
+    for seed in 0..50 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+        let game_result = res.game_result.unwrap();
+
+        for (i, team) in teams.iter().enumerate() {
+            let stats = &game_result.teams_stats[i];
+            let lines = &game_result.players_stats[i];
+
+            assert_eq!(stats.saves, lines.iter().map(|l| l.saves).sum::<i32>());
+            assert_eq!(stats.claims, lines.iter().map(|l| l.claims).sum::<i32>());
+            assert_eq!(stats.clean_sheet, game_result.score[1 - i] == 0);
+
+            // Every goalkeeper keeps the clean sheet of the team, a goal only takes it
+            // from the ones on the pitch (see player_stats)
+            let goalkeepers: Vec<u8> = team.players
+                .iter()
+                .chain(team.bench_players.iter())
+                .filter(|p| p.position == Position::Goalkeeper)
+                .map(|p| p.number)
+                .collect();
+            for line in lines {
+                let is_goalkeeper = goalkeepers.contains(&line.player_number);
+                assert!(is_goalkeeper || !line.clean_sheet);
+                if stats.clean_sheet {
+                    assert_eq!(line.clean_sheet, is_goalkeeper);
+                }
+            }
+
+            if stats.clean_sheet {
+                clean_sheets += 1;
+            }
+        }
+
+        // Every save of a shot on target is logged
+        let saves = count(
+            &res.logs,
+            &[
+                "caught.shoot",
+                "parried.shoot",
+                "corner.shoot",
+                "caught.header",
+                "parried.header",
+                "corner.header",
+                "saved.free_kick",
+            ]
+        );
+        assert!(saves > 0);
+    }
+
+    assert!(clean_sheets > 0, "No clean sheets in 50 games");
+}
+
+#[test]
+fn test_handling_holds_more_shots() {
+    let caught_share = |handling: u8| {
+        let mut teams = load_alpha_55();
+        let goalkeeper = goalkeeper_index(&teams[1]);
+        teams[1].players[goalkeeper].skills.handling = handling;
+        let team_name = teams[0].name.clone();
+
+        let (mut caught, mut saved) = (0, 0);
+        for seed in 0..30 {
+            let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+            let logs: Vec<Log> = res.logs
+                .into_iter()
+                .filter(|l| l.team_name == team_name)
+                .collect();
+
+            caught += count(&logs, &["caught.shoot"]);
+            saved += count(&logs, &["caught.shoot", "parried.shoot", "corner.shoot", "fumbled.shoot"]);
+        }
+
+        (caught as f32) / (saved.max(1) as f32)
+    };
+
+    assert!(caught_share(95) > caught_share(20), "Handling must hold more shots");
+}
+
+#[test]
+fn test_goalkeepers_kick_long_with_kicking_and_tempo() {
+    let long_kicks = |kicking: u8, tempo: Tempo, opponent_pressing: Pressing| {
+        let mut teams = load_alpha_55();
+        let goalkeeper = goalkeeper_index(&teams[0]);
+        teams[0].players[goalkeeper].skills.kicking = kicking;
+        teams[0].tactics.tempo = tempo;
+        teams[1].tactics.pressing = opponent_pressing;
+        let name = teams[0].players[goalkeeper].name.clone();
+        let team_name = teams[0].name.clone();
+
+        let (mut long, mut total) = (0, 0);
+        for seed in 0..20 {
+            let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+            let logs: Vec<Log> = res.logs
+                .into_iter()
+                .filter(|l| l.team_name == team_name && l.player_name == name)
+                .collect();
+
+            long += count(&logs, &["success.long_pass", "failed.long_pass"]);
+            total += count(&logs, &["success.long_pass", "failed.long_pass", "success.pass", "failed.pass"]);
+        }
+
+        (long as f32) / (total.max(1) as f32)
+    };
+
+    let short_game = long_kicks(10, Tempo::Slow, Pressing::Low);
+    let long_game = long_kicks(95, Tempo::Fast, Pressing::High);
+
+    println!("Long kick share: short game={}, long game={}", short_game, long_game);
+    assert!(long_game > short_game + 0.3, "Kicking and tactics must decide the distribution");
+}
@@ -0,0 +1,135 @@
+use crate::models::game::game::Game;
+use crate::models::game::game_result::GameResult;
+use crate::models::game::team::Team;
+use crate::tests::load_alpha_55;
+use crate::models::game::team_stats::InjuryType;
+use crate::models::game::substitution::{ Substitution, MAX_SUBSTITUTIONS };
+use crate::models::player::actions::Actions;
+use crate::utils::generate_random_number::create_game_rng;
+
+/// Rolls injuries for the player 9 of the first team until one happens
+fn injure(teams: &mut [Team; 2], game_result: &mut GameResult, wanted: InjuryType) -> bool {
+    for seed in 0..5000 {
+        let mut attempt = teams.clone();
+        let mut attempt_result = game_result.clone();
+        let mut ball_possession = [0, 9];
+        let mut last_pass_player = [0, 8];
+        let mut logs = Vec::new();
+        let mut rng = create_game_rng(seed);
+
+        attempt[0].players[9].energy = 20.0;
+
+        let injured = Actions::injury(
+            &mut attempt,
+            &mut ball_possession,
+            &mut last_pass_player,
+            [0, 9],
+            99,
+            true,
+            &mut logs,
+            60,
+            &mut attempt_result,
+            &mut rng
+        );
+
+        if injured && attempt_result.injuries.last().unwrap().injury == wanted {
+            assert!((ball_possession[1] as usize) < attempt[0].players.len());
+            *teams = attempt;
+            *game_result = attempt_result;
+            return true;
+        }
+    }
+
+    false
+}
+
+#[test]
+fn test_soft_injury_reduces_skills() {
+    let mut teams = load_alpha_55();
+    let mut game_result = GameResult::create();
+    let speed = teams[0].players[9].skills.speed;
+
+    assert!(injure(&mut teams, &mut game_result, InjuryType::Soft));
+
+    assert_eq!(teams[0].players.len(), 11);
+    assert!(teams[0].players[9].skills.speed < speed);
+    assert!(game_result.injuries[0].days_out > 0);
+}
+
+#[test]
+fn test_hard_injury_forces_a_substitution() {
+    let mut teams = load_alpha_55();
+    let mut game_result = GameResult::create();
+    // Fixture bench players share names with the starters, compare numbers
+    let injured = teams[0].players[9].number;
+
+    assert!(injure(&mut teams, &mut game_result, InjuryType::Hard));
+
+    assert_eq!(teams[0].players.len(), 11);
+    assert_ne!(teams[0].players[9].number, injured);
+    assert_eq!(teams[0].substitutions_made.len(), 1);
+    assert!(teams[0].bench_players.iter().any(|p| p.number == injured));
+}
+
+#[test]
+fn test_hard_injury_without_substitutions_leaves_team_short() {
+    let mut teams = load_alpha_55();
+    let mut game_result = GameResult::create();
+    let injured = teams[0].players[9].name.clone();
+
+    for i in 0..MAX_SUBSTITUTIONS as u8 {
+        teams[0].substitutions_made.push(Substitution { minute: 45, player_out: i, player_in: i });
+    }
+
+    assert!(injure(&mut teams, &mut game_result, InjuryType::Hard));
+
+    assert_eq!(teams[0].players.len(), 10);
+    assert!(teams[0].players.iter().all(|p| p.name != injured));
+    assert_eq!(teams[0].injured_players.len(), 1);
+}
+
+#[test]
+fn test_injuries_during_full_games() {
+    let teams = load_alpha_55();
+
+    let games = 200;
+    let mut soft = 0;
+    let mut hard = 0;
+
+    for seed in 0..games {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+        let game_result = res.game_result.unwrap();
+
+        for injury in &game_result.injuries {
+            match injury.injury {
+                InjuryType::Soft => {
+                    soft += 1;
+                }
+                InjuryType::Hard => {
+                    hard += 1;
+                }
+            }
+
+            let description = match injury.injury {
+                InjuryType::Soft => "soft.injury",
+                InjuryType::Hard => "hard.injury",
+            };
+            assert!(
+                res.logs
+                    .iter()
+                    .any(|l| {
+                        l.minute == injury.minute &&
+                            l.player_name == injury.player_name &&
+                            l.description == description
+                    }),
+                "Injury without log"
+            );
+        }
+    }
+
+    println!("Over {} games: soft injuries = {}, hard injuries = {}", games, soft, hard);
+
+    assert!(soft > 0, "No soft injuries in {} games", games);
+    assert!(hard > 0, "No hard injuries in {} games", games);
+    assert!(soft + hard < games * 2, "Too many injuries: {}", soft + hard);
+}
@@ -0,0 +1,139 @@
+use crate::models::game::game::{ Game, GameReturn };
+use crate::models::game::match_event::{ MatchEvent, SubstitutionReason };
+use crate::models::game::match_mode::{ MatchMode, MatchOutcome };
+use crate::models::game::substitution::Substitution;
+use crate::tests::load_alpha_55;
+
+fn play(mode: MatchMode, seed: u64) -> GameReturn {
+    Game::create_game_with_mode(load_alpha_55(), Some(seed), mode, true).expect(
+        "Error creating game"
+    )
+}
+
+#[test]
+fn test_league_games_end_after_regular_time() {
+    let mut draws = 0;
+
+    for seed in 0..30 {
+        let res = play(MatchMode::League, seed);
+        let game_result = res.game_result.expect("Missing game result");
+
+        assert_eq!(game_result.outcome, MatchOutcome::RegularTime);
+        assert_eq!(game_result.added_time.len(), 2);
+        assert!(res.logs.iter().all(|l| l.minute <= 90 || l.minute == 255));
+
+        if game_result.score[0] == game_result.score[1] {
+            draws += 1;
+        }
+    }
+
+    assert!(draws > 0, "League games must be able to end in a draw");
+}
+
+#[test]
+fn test_knockout_games_always_have_a_winner() {
+    let mut extra_time = 0;
+    let mut penalties = 0;
+
+    for seed in 0..60 {
+        let res = play(MatchMode::Knockout, seed);
+        let game_result = res.game_result.expect("Missing game result");
+
+        assert!(game_result.winner().is_some(), "Knockout games can't end in a draw");
+
+        match &game_result.outcome {
+            MatchOutcome::RegularTime => {
+                assert_ne!(game_result.score[0], game_result.score[1]);
+                assert_eq!(game_result.added_time.len(), 2);
+            }
+            MatchOutcome::ExtraTime => {
+                extra_time += 1;
+                assert_ne!(game_result.score[0], game_result.score[1]);
+                assert_eq!(game_result.added_time.len(), 4, "Extra time has two halves");
+                assert!(res.logs.iter().any(|l| l.minute == 120));
+            }
+            MatchOutcome::Penalties { score } => {
+                penalties += 1;
+                assert_eq!(game_result.score[0], game_result.score[1]);
+                assert_ne!(score[0], score[1]);
+
+                let kicks: Vec<_> = res.logs
+                    .iter()
+                    .filter(|l| l.description.ends_with(".shootout"))
+                    .collect();
+                let scored = |team: usize| {
+                    kicks
+                        .iter()
+                        .filter(|l| l.team_name == load_alpha_55()[team].name)
+                        .filter(|l| l.description == "scored.shootout")
+                        .count() as u8
+                };
+                assert_eq!([scored(0), scored(1)], *score, "Every kick must be logged");
+                assert!(kicks.len() >= 6, "A shootout has at least three kicks each");
+            }
+        }
+    }
+
+    println!("Extra time: {}, penalties: {}", extra_time, penalties);
+    assert!(extra_time + penalties > 0, "No knockout draw in the whole run");
+}
+
+#[test]
+fn test_shootout_order_follows_penalty_takers() {
+    let mut team = load_alpha_55()[0].clone();
+    for player in team.players.iter_mut() {
+        player.instructions.penalty_kicker = 0;
+    }
+    team.players[8].instructions.penalty_kicker = 1;
+    team.players[3].instructions.penalty_kicker = 2;
+
+    let order = team.shootout_order();
+
+    assert_eq!(order.len(), team.players.len());
+    assert_eq!(&order[..2], &[8, 3]);
+}
+
+#[test]
+fn test_substitutions_in_extra_time() {
+    let mut teams = load_alpha_55();
+    teams[0].substitutions.push(Substitution { minute: 100, player_out: 5, player_in: 3 });
+
+    // Only knockout games get to minute 100
+    let err = Game::create_game(teams.clone(), Some(1)).unwrap_err();
+    assert_eq!(err, "Team 1 substitution error: Invalid substitution: minute 100 out of range 1-90");
+
+    let mut extra_time_games = 0;
+    for seed in 0..60 {
+        let res = Game::create_game_with_mode(teams.clone(), Some(seed), MatchMode::Knockout, false)
+            .expect("Error creating game");
+        let game_result = res.game_result.expect("Missing game result");
+        if game_result.outcome == MatchOutcome::RegularTime {
+            continue;
+        }
+
+        // An injury substitution of the same player drops the planned one
+        let replaced_injured = res.events.iter().any(|e| {
+            matches!(
+                &e.event,
+                MatchEvent::Substitution { player_out, reason: SubstitutionReason::Injury, .. }
+                    if player_out.team == 0 && player_out.index == 5
+            )
+        });
+        if replaced_injured {
+            continue;
+        }
+        extra_time_games += 1;
+
+        let substitution = res.events
+            .iter()
+            .find(|e| e.minute == 100 && matches!(e.event, MatchEvent::Substitution { .. }))
+            .expect("The extra time substitution must be made");
+        if let MatchEvent::Substitution { player_out, player_in, .. } = &substitution.event {
+            assert_eq!(player_out.team, 0);
+            assert_eq!(player_out.name, teams[0].players[5].name);
+            assert_eq!(player_in.name, teams[0].bench_players[3].name);
+        }
+    }
+
+    assert!(extra_time_games > 0, "No knockout game went to extra time");
+}
@@ -0,0 +1,76 @@
+use serde_json::from_str;
+use crate::handlers::game::simulate_game::handle_simulate_game;
+use crate::models::game::live::{ DEFAULT_LIVE_SCALE, LiveControl, LiveFrame, LiveRequest };
+use crate::models::game::match_event::{ EventRecord, MatchEvent };
+use crate::models::game::match_mode::MatchMode;
+use crate::tests::load_alpha_55;
+use crate::services::game::live_frames;
+
+#[test]
+fn test_live_frames_follow_the_match() {
+    let teams = load_alpha_55();
+
+    for (seed, mode) in [(1, MatchMode::League), (2, MatchMode::Knockout), (3, MatchMode::League)] {
+        let game = handle_simulate_game(teams.clone(), Some(seed), mode, false, Some("en")).expect(
+            "Error creating game"
+        );
+        let all_events = game.events.clone();
+        let all_commentary = game.commentary.clone();
+        let score = game.game_result.as_ref().expect("Missing game result").score;
+
+        let frames = live_frames(game);
+        let (summary, minutes) = frames.split_last().expect("No frames");
+
+        // Every event and commentary line is sent once and in order
+        let mut events: Vec<EventRecord> = Vec::new();
+        let mut commentary = Vec::new();
+        for frame in minutes {
+            match frame {
+                LiveFrame::Minute { minute, added_minute, events: frame_events, commentary: lines } => {
+                    assert!(!frame_events.is_empty(), "Minutes without events are not sent");
+                    assert!(
+                        frame_events
+                            .iter()
+                            .any(|e| e.minute == *minute && e.added_minute == *added_minute)
+                    );
+                    assert!(lines.iter().all(|l| frame_events.iter().any(|e| e.sequence == l.sequence)));
+                    events.extend(frame_events.iter().cloned());
+                    commentary.extend(lines.iter().cloned());
+                }
+                _ => panic!("Only minutes before the summary"),
+            }
+        }
+        assert_eq!(events, all_events);
+        assert_eq!(commentary, all_commentary);
+
+        // The match opens with the kick-off
+        if let LiveFrame::Minute { minute, events, .. } = &frames[0] {
+            assert_eq!(*minute, 0);
+            assert_eq!(events[0].event, MatchEvent::Start);
+            assert!(matches!(events[1].event, MatchEvent::KickOff { .. }));
+        }
+
+        match summary {
+            LiveFrame::Summary { game_result, seed: summary_seed, .. } => {
+                assert_eq!(game_result.as_ref().expect("Missing game result").score, score);
+                assert_eq!(*summary_seed, seed);
+            }
+            _ => panic!("The summary is the last frame"),
+        }
+    }
+}
+
+#[test]
+fn test_live_messages() {
+    let request: LiveRequest = from_str(r#"{ "teams": [], "seed": 4 }"#).expect("Invalid request");
+    assert_eq!(request.scale, DEFAULT_LIVE_SCALE);
+    assert!(request.lang.is_none());
+
+    let control: LiveControl = from_str(r#"{ "control": "FastForward" }"#).expect("Invalid control");
+    assert_eq!(control, LiveControl::FastForward);
+    assert!(from_str::<LiveControl>(r#"{ "control": "Pause" }"#).is_err());
+
+    let frame = serde_json::to_value(LiveFrame::Error { message: "Oops".to_string() }).unwrap();
+    assert_eq!(frame["frame"], "Error");
+    assert_eq!(frame["message"], "Oops");
+}
@@ -0,0 +1,169 @@
+use serde_json::from_str;
+use crate::models::game::game::Game;
+use crate::models::game::log::Log;
+use crate::models::game::manager_rule::{ ManagerRule, RuleAction, RuleCondition };
+use crate::models::game::tactics::{ Mentality, Tempo };
+use crate::tests::load_alpha_55;
+use crate::models::player::position::Position;
+
+fn rule(when: RuleCondition, then: Vec<RuleAction>) -> ManagerRule {
+    ManagerRule { when, then, fired: false }
+}
+
+fn fired_rules<'a>(logs: &'a [Log], team_name: &str) -> Vec<&'a Log> {
+    logs.iter()
+        .filter(|l| l.description == "fired.manager_rule" && l.team_name == team_name)
+        .collect()
+}
+
+/// Goals of a team logged before the log at `index`
+fn goals_before(logs: &[Log], index: usize, team_name: &str) -> i8 {
+    logs[..index]
+        .iter()
+        .filter(|l| l.team_name == team_name && l.description.starts_with("goal."))
+        .count() as i8
+}
+
+#[test]
+fn test_rules_deserialize() {
+    let json =
+        r#"{
+        "when": { "from_minute": 70, "max_goal_difference": -1 },
+        "then": [
+            { "Mentality": "Attacking" },
+            { "Substitution": { "player_out": 10, "player_in": 6 } },
+            { "Position": { "player": 9, "position": "Striker" } }
+        ]
+    }"#;
+    let rule: ManagerRule = from_str(json).expect("Rule deserialization failed");
+
+    assert_eq!(rule.when.from_minute, Some(70));
+    assert_eq!(rule.when.max_goal_difference, Some(-1));
+    assert_eq!(rule.when.min_red_cards, None);
+    assert_eq!(rule.then[0], RuleAction::Mentality(Mentality::Attacking));
+    assert!(!rule.fired);
+}
+
+#[test]
+fn test_rule_fires_once_and_applies_its_actions() {
+    let mut teams = load_alpha_55();
+    teams[0].manager_rules = vec![
+        rule(
+            RuleCondition { from_minute: Some(60), ..Default::default() },
+            vec![
+                RuleAction::Tempo(Tempo::Slow),
+                RuleAction::Substitution { player_out: 10, player_in: 5 },
+                RuleAction::Position { player: 9, position: Position::Defensive_Midfield }
+            ]
+        )
+    ];
+    let team_name = teams[0].name.clone();
+
+    for seed in 0..10 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+
+        let fired = fired_rules(&res.logs, &team_name);
+        assert_eq!(fired.len(), 1, "A rule fires only once");
+        assert_eq!(fired[0].minute, 60);
+
+        let at_60 = |description: &str| {
+            res.logs
+                .iter()
+                .any(|l| l.minute == 60 && l.team_name == team_name && l.description == description)
+        };
+        assert!(at_60("substitution"));
+        assert!(at_60("change.position"));
+    }
+}
+
+#[test]
+fn test_score_conditions() {
+    let mut teams = load_alpha_55();
+    teams[0].manager_rules = vec![
+        rule(
+            RuleCondition { from_minute: Some(70), max_goal_difference: Some(-1), ..Default::default() },
+            vec![RuleAction::Mentality(Mentality::AllOutAttack)]
+        ),
+        rule(
+            RuleCondition { from_minute: Some(80), min_goal_difference: Some(2), ..Default::default() },
+            vec![RuleAction::Tempo(Tempo::Slow)]
+        )
+    ];
+    let team_name = teams[0].name.clone();
+    let opponent_name = teams[1].name.clone();
+    let mut fired = 0;
+
+    for seed in 0..40 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+
+        for (i, log) in res.logs.iter().enumerate() {
+            if log.description != "fired.manager_rule" || log.team_name != team_name {
+                continue;
+            }
+            fired += 1;
+
+            let difference =
+                goals_before(&res.logs, i, &team_name) - goals_before(&res.logs, i, &opponent_name);
+            assert!(log.minute >= 70);
+            assert!(difference <= -1 || (log.minute >= 80 && difference >= 2));
+        }
+    }
+
+    assert!(fired > 0, "The score rules never fired");
+}
+
+#[test]
+fn test_red_card_condition() {
+    let mut teams = load_alpha_55();
+    for player in teams[0].players.iter_mut() {
+        player.skills.aggression = 99;
+    }
+    teams[0].manager_rules = vec![
+        rule(
+            RuleCondition { min_red_cards: Some(1), to_minute: Some(89), ..Default::default() },
+            vec![RuleAction::Mentality(Mentality::Defensive)]
+        )
+    ];
+    let team_name = teams[0].name.clone();
+    let mut reds = 0;
+
+    for seed in 0..30 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+
+        let first_red = res.logs.iter().position(|l| {
+            l.team_name == team_name &&
+                l.added_minute == 0 &&
+                l.minute < 89 &&
+                (l.description == "red_card.foul" || l.description == "second_yellow_card.foul")
+        });
+        let fired = res.logs
+            .iter()
+            .position(|l| l.team_name == team_name && l.description == "fired.manager_rule");
+
+        match (first_red, fired) {
+            (Some(red), Some(rule)) => {
+                reds += 1;
+                assert!(rule > red, "The rule fired before the red card");
+            }
+            (None, None) => {}
+            (Some(_), None) => panic!("A red card before minute 89 must fire the rule"),
+            (None, Some(_)) => {}
+        }
+    }
+
+    assert!(reds > 0, "No red cards were shown");
+}
+
+#[test]
+fn test_invalid_rule_is_rejected() {
+    let mut teams = load_alpha_55();
+    teams[1].manager_rules = vec![
+        rule(RuleCondition::default(), vec![RuleAction::Substitution { player_out: 20, player_in: 0 }])
+    ];
+
+    let result = Game::create_game(teams, Some(1));
+    assert_eq!(
+        result.err().unwrap(),
+        "Team 2 manager rule error: Invalid manager rule 0: player_out 20 does not exist"
+    );
+}
@@ -0,0 +1,106 @@
+use crate::models::game::game::Game;
+use crate::models::game::match_event::{ EventRecord, GoalKind, MatchEvent };
+use crate::tests::load_alpha_55;
+
+#[test]
+fn test_events_are_numbered_in_order() {
+    let teams = load_alpha_55();
+
+    for seed in 0..10 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("Error creating game");
+
+        assert_eq!(res.events[0].event, MatchEvent::Start);
+        for (i, event) in res.events.iter().enumerate() {
+            assert_eq!(event.sequence, i as u32, "Sequence numbers must follow the events");
+        }
+    }
+}
+
+#[test]
+fn test_events_serialize_with_their_type() {
+    let teams = load_alpha_55();
+    let res = Game::create_game(teams, Some(3)).expect("Error creating game");
+
+    let json = serde_json::to_value(&res).expect("Error serializing game");
+    let events = json["events"].as_array().expect("Missing events");
+
+    assert_eq!(events[0]["type"], "Start");
+    assert_eq!(events[1]["type"], "KickOff");
+    assert!(events.iter().all(|e| e["type"].is_string() && e["sequence"].is_u64()));
+
+    // Events read back the same
+    let back: Vec<EventRecord> = serde_json::from_value(json["events"].clone()).expect(
+        "Error deserializing events"
+    );
+    assert_eq!(back, res.events);
+}
+
+#[test]
+fn test_legacy_logs_follow_the_events() {
+    let teams = load_alpha_55();
+
+    for seed in 0..10 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("Error creating game");
+
+        // Every event gives a log, assisted goals also log the assist
+        let assisted = res.events
+            .iter()
+            .filter(|e| matches!(&e.event, MatchEvent::Goal { assistant: Some(_), .. }))
+            .count();
+        assert_eq!(res.logs.len(), res.events.len() + assisted);
+
+        let descriptions: Vec<String> = res.events
+            .iter()
+            .map(|e| e.description())
+            .collect();
+        let logged: Vec<&String> = res.logs
+            .iter()
+            .filter(|l| l.description != "assist.goal")
+            .map(|l| &l.description)
+            .collect();
+        assert_eq!(logged, descriptions.iter().collect::<Vec<_>>());
+
+        assert!(res.logs.iter().any(|l| l.description == "start.second_half"));
+    }
+}
+
+#[test]
+fn test_goal_events_carry_the_shot() {
+    let teams = load_alpha_55();
+
+    let mut open_play = 0;
+    for seed in 0..20 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("Error creating game");
+        let game_result = res.game_result.expect("Missing game result");
+
+        let goals: Vec<&MatchEvent> = res.events
+            .iter()
+            .map(|e| &e.event)
+            .filter(|e| matches!(e, MatchEvent::Goal { .. }))
+            .collect();
+        assert_eq!(goals.len() as u8, game_result.score[0] + game_result.score[1]);
+
+        for goal in goals {
+            if let MatchEvent::Goal { kind, zone, xg, assistant, scorer } = goal {
+                match kind {
+                    GoalKind::Shot | GoalKind::Header => {
+                        open_play += 1;
+                        assert!(zone.is_some(), "Open play goals have a zone");
+                        assert!(xg.is_some_and(|xg| xg > 0.0 && xg <= 1.0), "Open play goals have an xG");
+                    }
+                    _ => {
+                        assert!(zone.is_none() && xg.is_none());
+                        assert!(assistant.is_none(), "Set pieces are not assisted");
+                    }
+                }
+
+                if let Some(assistant) = assistant {
+                    assert_eq!(assistant.team, scorer.team);
+                    assert_ne!(assistant.index, scorer.index);
+                }
+            }
+        }
+    }
+
+    assert!(open_play > 0, "No open play goals in 20 games");
+}
@@ -1,3 +1,10 @@
+#[cfg(test)]
+use serde::Deserialize;
+#[cfg(test)]
+use crate::models::game::substitution::Substitution;
+#[cfg(test)]
+use crate::models::game::team::Team;
+
 pub mod shoot;
 pub mod pass;
 pub mod dribble;
@@ -6,4 +13,79 @@ pub mod long_pass;
 pub mod control;
 pub mod finish;
 pub mod corner;
-pub mod full_game;
\ No newline at end of file
+pub mod full_game;
+#[cfg(test)]
+pub mod batch;
+
+#[cfg(test)]
+pub mod substitution;
+#[cfg(test)]
+pub mod fatigue;
+#[cfg(test)]
+pub mod fouls;
+#[cfg(test)]
+pub mod free_kick;
+#[cfg(test)]
+pub mod penalty;
+#[cfg(test)]
+pub mod set_piece;
+#[cfg(test)]
+pub mod injuries;
+#[cfg(test)]
+pub mod team_stats;
+#[cfg(test)]
+pub mod player_stats;
+#[cfg(test)]
+pub mod ratings;
+#[cfg(test)]
+pub mod pass_matrix;
+#[cfg(test)]
+pub mod defensive_instructions;
+#[cfg(test)]
+pub mod offside;
+#[cfg(test)]
+pub mod stoppage_time;
+#[cfg(test)]
+pub mod knockout;
+#[cfg(test)]
+pub mod tactics;
+
+#[cfg(test)]
+pub mod manager_rules;
+#[cfg(test)]
+pub mod expected_goals;
+#[cfg(test)]
+pub mod goalkeeping;
+#[cfg(test)]
+pub mod match_events;
+#[cfg(test)]
+pub mod commentary;
+#[cfg(test)]
+pub mod live;
+
+#[cfg(test)]
+#[derive(Deserialize)]
+struct TeamsWrapper {
+    teams: [Team; 2],
+}
+
+/// Both teams of a fixture in `src/tests/fixtures`, like `team-alpha-55.json`
+#[cfg(test)]
+pub fn load_teams(fixture: &str) -> [Team; 2] {
+    let file_str = std::fs
+        ::read_to_string(format!("src/tests/fixtures/{}", fixture))
+        .unwrap_or_else(|_| panic!("Failed to read {}", fixture));
+    let wrapper: TeamsWrapper = serde_json::from_str(&file_str).expect("JSON deserialization failed");
+    wrapper.teams
+}
+
+/// Teams of `team-alpha-55.json` ready to play a match. The fixture subs out a player 55
+/// that doesn't exist at half time, rejected since substitutions are validated
+#[cfg(test)]
+pub fn load_alpha_55() -> [Team; 2] {
+    let mut teams = load_teams("team-alpha-55.json");
+    for team in teams.iter_mut() {
+        team.substitutions[0] = Substitution { minute: 45, player_out: 0, player_in: 0 };
+    }
+    teams
+}
@@ -0,0 +1,100 @@
+use crate::models::game::game::Game;
+use crate::models::game::team::Team;
+use crate::tests::load_alpha_55;
+use crate::models::player::instructions::DefensiveInstruction;
+use crate::models::player::position::Position;
+use crate::logics::player::offside::is_defensive_line;
+
+/// The defensive line of the second team plays the offside trap, or not
+fn set_offside_trap(teams: &mut [Team; 2], trap: bool) {
+    for player in teams[1].players.iter_mut().chain(teams[1].bench_players.iter_mut()) {
+        player.instructions.defensive.retain(|i| *i != DefensiveInstruction::Offside);
+        if trap && is_defensive_line(&player.position) {
+            player.instructions.defensive.push(DefensiveInstruction::Offside);
+        }
+    }
+}
+
+/// Offsides of the first team and times it beat the trap over some games
+fn play(teams: &[Team; 2]) -> (i32, usize) {
+    let mut offsides = 0;
+    let mut beaten = 0;
+
+    for seed in 0..40 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("Error creating game");
+        let game_result = res.game_result.expect("Missing game result");
+
+        let flagged = res.logs
+            .iter()
+            .filter(|l| l.description.starts_with("offside.") && l.team_name == teams[0].name)
+            .count();
+        assert_eq!(flagged as i32, game_result.teams_stats[0].offsides, "Every offside must be logged");
+
+        let restarts = res.logs
+            .iter()
+            .filter(|l| l.description == "indirect.free_kick")
+            .count() as i32;
+        assert_eq!(
+            restarts,
+            game_result.teams_stats[0].offsides + game_result.teams_stats[1].offsides,
+            "Every offside must give an indirect free kick"
+        );
+
+        offsides += game_result.teams_stats[0].offsides;
+        beaten += res.logs
+            .iter()
+            .filter(|l| l.description == "beaten.offside_trap" && l.team_name == teams[0].name)
+            .count();
+    }
+
+    (offsides, beaten)
+}
+
+#[test]
+fn test_offside_trap_catches_more_offsides() {
+    let mut teams = load_alpha_55();
+
+    set_offside_trap(&mut teams, false);
+    let (without_trap, beaten_without_trap) = play(&teams);
+
+    set_offside_trap(&mut teams, true);
+    let (with_trap, _) = play(&teams);
+
+    println!("Offsides without trap: {}, with trap: {}", without_trap, with_trap);
+
+    assert!(without_trap > 0, "Forward runs must be flagged sometimes");
+    assert!(with_trap > without_trap, "The offside trap must catch more offsides");
+    assert_eq!(beaten_without_trap, 0, "There is no trap to beat without the Offside instruction");
+}
+
+#[test]
+fn test_fast_strikers_beat_the_trap() {
+    let mut teams = load_alpha_55();
+    set_offside_trap(&mut teams, true);
+
+    let (slow_offsides, slow_beaten) = play(&teams);
+
+    for player in teams[0].players.iter_mut() {
+        if matches!(player.position, Position::Striker | Position::Left_Wing | Position::Right_Wing) {
+            player.skills.speed = 99;
+        }
+    }
+    for player in teams[1].players.iter_mut() {
+        if is_defensive_line(&player.position) {
+            player.skills.speed = 30;
+        }
+    }
+
+    let (fast_offsides, fast_beaten) = play(&teams);
+
+    println!(
+        "Slow strikers: {} offsides, {} beaten. Fast strikers: {} offsides, {} beaten",
+        slow_offsides,
+        slow_beaten,
+        fast_offsides,
+        fast_beaten
+    );
+
+    assert!(fast_beaten > slow_beaten, "Fast strikers must beat the trap more often");
+    assert!(fast_offsides < slow_offsides, "Fast strikers must be caught less often");
+}
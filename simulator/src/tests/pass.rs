@@ -24,10 +24,13 @@ mod tests {
     use crate::models::player::status::Status;
     use crate::models::player::instructions::Instructions;
     use crate::models::player::stats::Stats;
+    use crate::models::player::match_stats::PlayerMatchStats;
     use crate::models::game::team::Team;
+    use crate::models::game::tactics::Tactics;
     use crate::models::game::game_result::GameResult;
-    use crate::models::game::log::Log;
+    use crate::models::game::match_event::EventRecord;
     use crate::models::player::actions::Actions;
+    use crate::utils::generate_random_number::{ create_game_rng, generate_seed };
 
     // Helper to create a dummy player
     fn create_dummy_player(number: u8, position: Position, country: Country) -> Player {
@@ -45,6 +48,8 @@ mod tests {
             status: Status::generate_default_status(),
             instructions: Instructions::generate_empty_instructions(),
             stats: Stats::generate_default_status(),
+            energy: 100.0,
+            match_stats: PlayerMatchStats::default(),
         }
     }
 
@@ -70,14 +75,21 @@ mod tests {
             aura: ["NONE".to_string(), "NONE".to_string(), "NONE".to_string()],
             bench_players: vec![],
             player_name: "Dummy".to_string(),
+            tactics: Tactics::default(),
+            substitutions: vec![],
+            manager_rules: vec![],
+            substitutions_made: vec![],
+            substitution_windows: vec![],
+            sent_off_players: vec![],
+            injured_players: vec![],
         }
     }
 
     // Create a test game
-    fn create_test_game() -> ([Team; 2], GameResult, Vec<Log>, [u8; 2], [u8; 2]) {
+    fn create_test_game() -> ([Team; 2], GameResult, Vec<EventRecord>, [u8; 2], [u8; 2]) {
         let teams = [create_dummy_team(), create_dummy_team()];
-        let game_result = GameResult { score: [0, 0] };
-        let logs: Vec<Log> = vec![];
+        let game_result = GameResult::create();
+        let logs: Vec<EventRecord> = vec![];
         let ball_possession = [0, 10]; // striker will shoot
         let last_pass_player = [0, 9]; // dummy last passer
 
@@ -91,8 +103,10 @@ mod tests {
 
         let attempts = 1000;
 
+        let mut rng = create_game_rng(generate_seed());
+
         for _ in 0..attempts {
-            let (mut teams, _game_result, mut logs, mut ball_possession, mut last_pass_player) =
+            let (mut teams, mut game_result, mut logs, mut ball_possession, mut last_pass_player) =
                 create_test_game();
 
             let result = Actions::pass(
@@ -100,14 +114,16 @@ mod tests {
                 &mut ball_possession,
                 &mut last_pass_player,
                 &mut logs,
-                10
+                10,
+                &mut game_result,
+                &mut rng
             );
 
             assert!(result);
 
             // Count only pass-related outcomes
             for log in &logs {
-                match log.description.as_str() {
+                match log.description().as_str() {
                     "success.pass" => {
                         successful_passes += 1;
                     }
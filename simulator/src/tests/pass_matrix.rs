@@ -0,0 +1,96 @@
+use crate::models::game::team::Team;
+use crate::tests::load_teams;
+use crate::models::player::actions::Actions;
+use crate::models::player::position::Position;
+use crate::utils::generate_random_number::create_game_rng;
+
+fn position_index(team: &Team, position: Position) -> usize {
+    team.players
+        .iter()
+        .position(|p| p.current_position == position)
+        .expect("Fixture misses a position")
+}
+
+/// How many times every player of the first team receives a pass from `passer`
+fn count_receivers(teams: &[Team; 2], passer: usize, long_pass: bool) -> Vec<u32> {
+    let mut rng = create_game_rng(13);
+    let mut received = vec![0; teams[0].players.len()];
+
+    for _ in 0..5000 {
+        let (team_id, receiver) = Actions::select_pass_target(
+            teams,
+            &[0, passer as u8],
+            long_pass,
+            &mut rng
+        );
+
+        assert_eq!(team_id, 0, "A pass must go to a teammate");
+        assert_ne!(receiver as usize, passer, "A player can't pass to themself");
+        received[receiver as usize] += 1;
+    }
+
+    received
+}
+
+#[test]
+fn test_pass_follows_positions_not_array_order() {
+    let teams = load_teams("team-alpha-99.json");
+    let right_back = position_index(&teams[0], Position::Right_Back);
+    let left_back = position_index(&teams[0], Position::Left_Back);
+    let right_midfield = position_index(&teams[0], Position::Right_Midfield);
+    let striker = position_index(&teams[0], Position::Striker);
+
+    let received = count_receivers(&teams, right_back, false);
+    println!("Right back passes: {:?}", received);
+
+    assert!(received[left_back] > received[right_midfield]);
+    assert!(received[left_back] > received[striker]);
+}
+
+#[test]
+fn test_long_pass_favours_distant_lines() {
+    let teams = load_teams("team-alpha-99.json");
+    let defender = position_index(&teams[0], Position::Defender);
+    let goalkeeper = position_index(&teams[0], Position::Goalkeeper);
+    let striker = position_index(&teams[0], Position::Striker);
+
+    let short = count_receivers(&teams, defender, false);
+    let long = count_receivers(&teams, defender, true);
+
+    assert!(long[striker] > short[striker]);
+    assert!(long[goalkeeper] < short[goalkeeper]);
+    assert!(long[striker] > long[goalkeeper]);
+}
+
+#[test]
+fn test_receivers_use_current_position() {
+    let mut teams = load_teams("team-alpha-99.json");
+    let goalkeeper = position_index(&teams[0], Position::Goalkeeper);
+    let left_back = position_index(&teams[0], Position::Left_Back);
+
+    let before = count_receivers(&teams, goalkeeper, false);
+    teams[0].players[left_back].current_position = Position::Striker;
+    let after = count_receivers(&teams, goalkeeper, false);
+
+    assert!(after[left_back] < before[left_back]);
+}
+
+#[test]
+fn test_marked_receivers_are_avoided() {
+    let mut teams = load_teams("team-alpha-99.json");
+    let defender = position_index(&teams[0], Position::Defender);
+    let striker = position_index(&teams[0], Position::Striker);
+
+    // Nobody of the first team is marked
+    for player in teams[1].players.iter_mut() {
+        player.skills.defense = 0;
+    }
+    let free = count_receivers(&teams, defender, true);
+
+    // The striker gets a man-marker
+    let marker = position_index(&teams[1], Position::Defender);
+    teams[1].players[marker].skills.defense = 99;
+    let marked = count_receivers(&teams, defender, true);
+
+    assert!(marked[striker] < free[striker]);
+}
@@ -0,0 +1,99 @@
+use crate::models::game::game::Game;
+use crate::models::game::set_piece::SetPiece;
+use crate::models::game::game_result::GameResult;
+use crate::tests::load_alpha_55;
+use crate::models::player::actions::Actions;
+use crate::utils::generate_random_number::create_game_rng;
+
+#[test]
+fn test_designated_penalty_taker() {
+    let mut team = load_alpha_55()[0].clone();
+
+    for player in team.players.iter_mut() {
+        player.instructions.penalty_kicker = 0;
+    }
+    team.players[10].skills.kicking = 99;
+    team.players[10].skills.composure = 99;
+    assert_eq!(team.set_piece_taker(SetPiece::Penalty), 10);
+
+    team.players[2].instructions.penalty_kicker = 1;
+    assert_eq!(team.set_piece_taker(SetPiece::Penalty), 2);
+}
+
+#[test]
+fn test_penalty_goal_counts_in_the_score() {
+    let teams = load_alpha_55();
+
+    let mut goals = 0;
+    for seed in 0..200 {
+        let mut teams = teams.clone();
+        let mut ball_possession = [1, 9];
+        let mut last_pass_player = [1, 8];
+        let mut logs = Vec::new();
+        let mut game_result = GameResult::create();
+        let mut rng = create_game_rng(seed);
+
+        Actions::penalty(
+            &mut teams,
+            &mut ball_possession,
+            &mut last_pass_player,
+            &mut logs,
+            80,
+            &mut game_result,
+            &mut rng
+        );
+
+        // Every penalty is a shot on target of the taker
+        assert_eq!(game_result.teams_stats[1].shoots, 1);
+        assert_eq!(game_result.teams_stats[1].shoots_suc, 1);
+        assert_eq!(teams[1].players[9].match_stats.shoots, 1);
+        assert_eq!(teams[1].players[9].match_stats.shoots_suc, 1);
+
+        if logs[0].description() == "goal.penalty" {
+            goals += 1;
+            assert_eq!(game_result.score, [0, 1]);
+            assert_eq!(ball_possession[0], 0, "The conceding team restarts");
+        }
+    }
+
+    assert!(goals > 0, "No penalty goals in 200 tries");
+}
+
+#[test]
+fn test_penalties_awarded_in_full_games() {
+    let teams = load_alpha_55();
+
+    let games = 200;
+    let mut awarded = 0;
+    let mut scored = 0;
+
+    for seed in 0..games {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+        let score = res.game_result.unwrap().score;
+
+        for (i, log) in res.logs.iter().enumerate() {
+            if log.description != "awarded.penalty" {
+                continue;
+            }
+            awarded += 1;
+
+            // The awarded taker is the one who shoots
+            let shot = &res.logs[i + 1];
+            assert_eq!(shot.player_name, log.player_name);
+            assert!(shot.description.ends_with(".penalty"));
+        }
+
+        let goals = res.logs
+            .iter()
+            .filter(|l| l.description == "goal.penalty")
+            .count();
+        scored += goals;
+
+        assert!(goals <= (score[0] as usize) + (score[1] as usize), "Penalty goals missing in the score");
+    }
+
+    println!("Over {} games: penalties = {}, penalty goals = {}", games, awarded, scored);
+
+    assert!(awarded > 0, "No penalties in {} games", games);
+    assert!(awarded < (games as usize) * 2, "Too many penalties: {}", awarded);
+}
@@ -0,0 +1,148 @@
+use crate::models::game::game::Game;
+use crate::models::game::match_event::MatchEvent;
+use crate::models::game::substitution::Substitution;
+use crate::tests::load_alpha_55;
+
+#[test]
+fn test_goals_and_assists_are_credited() {
+    let teams = load_alpha_55();
+
+    let mut assists = 0;
+    for seed in 0..100 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+        let game_result = res.game_result.unwrap();
+
+        for i in 0..2 {
+            let lines = &game_result.players_stats[i];
+            let goals: i32 = lines.iter().map(|l| l.goals).sum();
+            let team_assists: i32 = lines.iter().map(|l| l.assists).sum();
+
+            assert_eq!(goals, game_result.score[i] as i32, "Every goal must have a scorer");
+            assert_eq!(team_assists as usize, game_result.teams_stats[i].assistants.len());
+            assists += team_assists;
+        }
+
+        // An assist always comes right after an open play goal of the same team
+        for (i, log) in res.logs.iter().enumerate() {
+            if log.description != "assist.goal" {
+                continue;
+            }
+            let goal = &res.logs[i - 1];
+            assert!(
+                goal.description == "goal.shoot" || goal.description == "goal.header",
+                "Only open play goals are assisted"
+            );
+            assert_eq!(goal.team_name, log.team_name);
+            assert_ne!(goal.player_number, log.player_number, "Nobody assists their own goal");
+        }
+    }
+
+    assert!(assists > 0, "No assists in 100 games");
+}
+
+#[test]
+fn test_player_passes_and_shots_add_up_to_the_team() {
+    let teams = load_alpha_55();
+
+    for seed in 0..20 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+        let game_result = res.game_result.unwrap();
+
+        for i in 0..2 {
+            let lines = &game_result.players_stats[i];
+            let stats = &game_result.teams_stats[i];
+
+            assert_eq!(lines.iter().map(|l| l.passes).sum::<i32>(), stats.passes);
+            assert_eq!(lines.iter().map(|l| l.passes_suc).sum::<i32>(), stats.passes_suc);
+            assert_eq!(lines.iter().map(|l| l.shoots).sum::<i32>(), stats.shoots);
+            assert_eq!(lines.iter().map(|l| l.shoots_suc).sum::<i32>(), stats.shoots_suc);
+        }
+    }
+}
+
+#[test]
+fn test_minutes_played() {
+    let mut teams = load_alpha_55();
+    teams[0].substitutions = vec![Substitution { minute: 60, player_out: 9, player_in: 9 }];
+    teams[1].substitutions.clear();
+
+    let outgoing = teams[0].players[9].number;
+    let incoming = teams[0].bench_players[9].number;
+
+    // First match where nobody is injured or sent off
+    let game_result = (0..50)
+        .map(|seed| {
+            Game::create_game(teams.clone(), Some(seed))
+                .expect("create_game failed")
+                .game_result.unwrap()
+        })
+        .find(|r| r.injuries.is_empty() && r.bookings.iter().all(|b| !b.card.is_sending_off()))
+        .expect("Every match had injuries or red cards");
+
+    let lines = &game_result.players_stats[0];
+    assert_eq!(lines.len(), 12, "Starters and the substitute took part");
+
+    let minutes = |number: u8| -> u8 {
+        lines
+            .iter()
+            .find(|l| l.player_number == number)
+            .map(|l| l.minutes_played)
+            .unwrap()
+    };
+    assert_eq!(minutes(outgoing), 60);
+    assert_eq!(minutes(incoming), 30);
+    assert_eq!(minutes(teams[0].players[0].number), 90);
+
+    assert_eq!(game_result.players_stats[1].len(), 11);
+}
+
+#[test]
+fn test_clean_sheet_of_each_goalkeeper() {
+    // Both teams change the goalkeeper at half time
+    let teams = load_alpha_55();
+    let keeper = |team: usize, bench: bool| {
+        let players = if bench { &teams[team].bench_players } else { &teams[team].players };
+        players[0].number
+    };
+
+    let mut split = 0;
+    for seed in 0..100 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+        let game_result = res.game_result.unwrap();
+
+        // Goalkeeper on the pitch for every goal, following the order of the events
+        let mut in_goal = [keeper(0, false), keeper(1, false)];
+        let mut conceded: Vec<(usize, u8)> = Vec::new();
+        for record in &res.events {
+            match &record.event {
+                MatchEvent::Substitution { player_out, player_in, .. } if player_out.index == 0 => {
+                    in_goal[player_out.team as usize] = player_in.number;
+                }
+                MatchEvent::Goal { scorer, .. } => {
+                    let team = 1 - scorer.team as usize;
+                    conceded.push((team, in_goal[team]));
+                }
+                _ => {}
+            }
+        }
+
+        for team in 0..2 {
+            let clean_sheets: Vec<bool> = [keeper(team, false), keeper(team, true)]
+                .iter()
+                .filter_map(|number| {
+                    game_result.players_stats[team].iter().find(|l| l.player_number == *number)
+                })
+                .map(|l| {
+                    assert_eq!(l.clean_sheet, !conceded.contains(&(team, l.player_number)));
+                    l.clean_sheet
+                })
+                .collect();
+
+            if clean_sheets.len() == 2 && clean_sheets[0] != clean_sheets[1] {
+                split += 1;
+            }
+        }
+    }
+
+    assert!(split > 0, "No match where only one of the goalkeepers kept a clean sheet");
+}
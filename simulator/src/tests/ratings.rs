@@ -0,0 +1,38 @@
+use crate::models::game::game::Game;
+use crate::tests::load_alpha_55;
+
+#[test]
+fn test_every_participant_gets_a_rating() {
+    let teams = load_alpha_55();
+
+    let mut sum = 0.0;
+    let mut count = 0;
+    for seed in 0..50 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+        let game_result = res.game_result.unwrap();
+
+        for line in game_result.players_stats.iter().flatten() {
+            assert!((0.0..=10.0).contains(&line.rating), "Rating out of range: {}", line.rating);
+            sum += line.rating;
+            count += 1;
+        }
+
+        // The man of the match is the best rated player
+        let best = res.man_of_the_match.expect("No man of the match");
+        let max = game_result.players_stats
+            .iter()
+            .flatten()
+            .map(|l| l.rating)
+            .fold(0.0, f32::max);
+        assert_eq!(best.rating, max);
+        assert!(
+            game_result.players_stats[best.team_index as usize]
+                .iter()
+                .any(|l| l.player_number == best.player_number)
+        );
+    }
+
+    let average = sum / (count as f32);
+    println!("Average rating over {} lines: {}", count, average);
+    assert!((5.0..8.0).contains(&average), "Average rating {} too far from the base", average);
+}
@@ -0,0 +1,65 @@
+use crate::models::game::set_piece::SetPiece;
+use crate::models::game::team::Team;
+use crate::tests::load_alpha_55;
+
+fn clear_designation(team: &mut Team) {
+    for player in team.players.iter_mut().chain(team.bench_players.iter_mut()) {
+        player.instructions.penalty_kicker = 0;
+        player.instructions.corner_kicker = 0;
+        player.instructions.free_kick_kicker = 0;
+    }
+}
+
+#[test]
+fn test_automatic_corner_taker() {
+    let mut team = load_alpha_55()[0].clone();
+    clear_designation(&mut team);
+
+    team.players[7].skills.crossing = 99;
+    assert_eq!(team.set_piece_taker(SetPiece::Corner), 7);
+
+    team.players[3].skills.crossing = 99;
+    team.players[3].skills.kicking = 99;
+    assert_eq!(team.set_piece_taker(SetPiece::Corner), 3);
+}
+
+#[test]
+fn test_ordered_takers() {
+    let mut team = load_alpha_55()[0].clone();
+    clear_designation(&mut team);
+
+    team.players[9].instructions.corner_kicker = 1;
+    team.players[5].instructions.corner_kicker = 2;
+    team.players[2].instructions.corner_kicker = 3;
+    team.players[2].skills.crossing = 99;
+
+    // Priority beats skill
+    assert_eq!(team.set_piece_taker(SetPiece::Corner), 9);
+
+    // Each set piece has its own list
+    team.players[4].instructions.penalty_kicker = 1;
+    assert_eq!(team.set_piece_taker(SetPiece::Penalty), 4);
+}
+
+#[test]
+fn test_taker_fallback_after_substitution_and_send_off() {
+    let mut team = load_alpha_55()[0].clone();
+    clear_designation(&mut team);
+
+    let first = team.players[9].name.clone();
+    let second = team.players[5].name.clone();
+    let third = team.players[2].name.clone();
+    team.players[9].instructions.free_kick_kicker = 1;
+    team.players[5].instructions.free_kick_kicker = 2;
+    team.players[2].instructions.free_kick_kicker = 3;
+
+    assert_eq!(team.players[team.set_piece_taker(SetPiece::FreeKick)].name, first);
+
+    // The first choice is substituted, the incoming player has no designation
+    team.substitute(9, 0, 60, false);
+    assert_eq!(team.players[team.set_piece_taker(SetPiece::FreeKick)].name, second);
+
+    // The second choice is sent off
+    team.send_off(5);
+    assert_eq!(team.players[team.set_piece_taker(SetPiece::FreeKick)].name, third);
+}
@@ -0,0 +1,60 @@
+use crate::models::game::game::Game;
+use crate::tests::load_alpha_55;
+use crate::logics::game::stoppage_time::{ MAX_STOPPAGE_TIME, MIN_STOPPAGE_TIME };
+
+#[test]
+fn test_stoppage_time_is_announced_and_played() {
+    let teams = load_alpha_55();
+    let mut total_added = [0u32; 2];
+
+    for seed in 0..30 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("Error creating game");
+        let game_result = res.game_result.expect("Missing game result");
+
+        let announcements: Vec<_> = res.logs
+            .iter()
+            .filter(|l| l.description == "added_time")
+            .collect();
+        assert_eq!(announcements.len(), 2, "Stoppage time must be announced at the end of each half");
+        assert_eq!(game_result.added_time.len(), 2);
+        assert_eq!((announcements[0].minute, announcements[1].minute), (45, 90));
+
+        for half in 0..2 {
+            let added = game_result.added_time[half];
+            assert_eq!(announcements[half].added_minute, added);
+            assert!((MIN_STOPPAGE_TIME..=MAX_STOPPAGE_TIME).contains(&added));
+            total_added[half] += added as u32;
+
+            // Actions are played in every added minute and never beyond the announced time
+            let played: Vec<u8> = res.logs
+                .iter()
+                .filter(|l| l.minute == announcements[half].minute && l.added_minute > 0)
+                .filter(|l| l.description != "added_time")
+                .map(|l| l.added_minute)
+                .collect();
+            assert!(played.iter().all(|m| *m <= added), "Logs beyond the stoppage time");
+            assert!(!played.is_empty(), "The stoppage time was not played");
+        }
+    }
+
+    println!("Average stoppage time: {:?}", total_added.map(|t| (t as f32) / 30.0));
+}
+
+#[test]
+fn test_more_events_more_stoppage_time() {
+    let teams = load_alpha_55();
+
+    let mut quiet_half = 0;
+    let mut busy_half = 0;
+
+    for seed in 0..30 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("Error creating game");
+        let game_result = res.game_result.expect("Missing game result");
+
+        // The second half has the substitutions of both teams
+        quiet_half += game_result.added_time[0] as u32;
+        busy_half += game_result.added_time[1] as u32;
+    }
+
+    assert!(busy_half > quiet_half, "Substitutions must add stoppage time");
+}
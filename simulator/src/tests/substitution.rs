@@ -0,0 +1,139 @@
+use crate::models::game::game::Game;
+use crate::models::game::substitution::Substitution;
+use crate::tests::{ load_alpha_55, load_teams };
+use crate::models::game::team_stats::InjuryType;
+
+#[test]
+fn test_scheduled_substitutions_are_applied() {
+    let mut teams = load_alpha_55();
+
+    teams[0].substitutions = vec![
+        Substitution { minute: 45, player_out: 10, player_in: 10 },
+        Substitution { minute: 60, player_out: 9, player_in: 9 },
+        Substitution { minute: 75, player_out: 8, player_in: 8 }
+    ];
+    teams[1].substitutions = vec![];
+
+    let incoming: Vec<String> = [10, 9, 8]
+        .iter()
+        .map(|i| teams[0].bench_players[*i].name.clone())
+        .collect();
+
+    for seed in 0..20 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+
+        // A forced substitution for a hard injury can use the planned windows
+        let forced = res.game_result
+            .as_ref()
+            .unwrap()
+            .injuries.iter()
+            .any(|i| i.team_index == 0 && i.injury == InjuryType::Hard);
+        // A sent off player drops their planned substitution
+        let sent_off = res.logs
+            .iter()
+            .any(|l| {
+                l.team_name == teams[0].name &&
+                    (l.description == "red_card.foul" || l.description == "second_yellow_card.foul")
+            });
+        if forced || sent_off {
+            continue;
+        }
+
+        let subs: Vec<_> = res.logs
+            .iter()
+            .filter(|l| l.description == "substitution")
+            .collect();
+
+        assert_eq!(subs.len(), 3, "Every scheduled substitution must be logged");
+        assert_eq!(
+            subs.iter().map(|l| l.minute).collect::<Vec<u8>>(),
+            vec![45, 60, 75],
+            "Substitutions must happen at the scheduled minute"
+        );
+        assert_eq!(
+            subs.iter().map(|l| l.player_name.clone()).collect::<Vec<String>>(),
+            incoming,
+            "The log must name the incoming player"
+        );
+        assert!(subs.iter().all(|l| l.team_name == teams[0].name));
+    }
+}
+
+#[test]
+fn test_substitute_takes_the_slot_of_the_outgoing_player() {
+    let mut team = load_alpha_55()[0].clone();
+
+    let outgoing = team.players[10].name.clone();
+    let incoming = team.bench_players[4].name.clone();
+    let slot_position = team.players[10].position.clone();
+
+    assert!(team.can_substitute(45, true));
+    team.substitute(10, 4, 45, true);
+
+    assert_eq!(team.players[10].name, incoming);
+    assert_eq!(team.players[10].position, slot_position);
+    assert_eq!(team.players[10].current_position, slot_position);
+    assert_eq!(team.bench_players[4].name, outgoing);
+    assert!(!team.is_bench_player_available(4), "A bench player can't enter twice");
+}
+
+#[test]
+fn test_substitution_limits() {
+    let mut team = load_alpha_55()[0].clone();
+
+    team.substitute(1, 1, 50, false);
+    team.substitute(2, 2, 60, false);
+    team.substitute(3, 3, 70, false);
+
+    // Every window is used, only an already opened stoppage is allowed
+    assert!(!team.can_substitute(80, false));
+    assert!(team.can_substitute(70, false));
+
+    team.substitute(4, 4, 70, false);
+    team.substitute(5, 5, 70, false);
+
+    assert!(!team.can_substitute(70, false), "Maximum substitutions reached");
+}
+
+#[test]
+fn test_first_half_stoppage_is_not_half_time() {
+    let mut team = load_alpha_55()[0].clone();
+
+    team.substitute(1, 1, 20, false);
+    team.substitute(2, 2, 30, false);
+
+    // An injury in the stoppage time of the first half uses the last window
+    assert!(team.can_substitute(45, false));
+    team.substitute(3, 3, 45, false);
+    assert_eq!(team.substitution_windows, vec![20, 30, 45]);
+    assert!(!team.can_substitute(60, false));
+
+    // The break before the second half is still free
+    assert!(team.can_substitute(45, true));
+    team.substitute(4, 4, 45, true);
+    assert_eq!(team.substitution_windows.len(), 3);
+}
+
+#[test]
+fn test_invalid_substitution_is_rejected() {
+    let mut teams = load_alpha_55();
+    teams[1].substitutions = vec![Substitution { minute: 45, player_out: 55, player_in: 0 }];
+
+    let err = Game::create_game(teams, None).unwrap_err();
+    assert_eq!(
+        err,
+        "Team 2 substitution error: Invalid substitution: player_out 55 does not exist"
+    );
+}
+
+#[test]
+fn test_fixture_with_missing_players_is_rejected() {
+    // The original fixture subs out player 55 of an 11 players lineup
+    let teams = load_teams("team-alpha-55.json");
+
+    let err = Game::create_game(teams, Some(1)).unwrap_err();
+    assert_eq!(
+        err,
+        "Team 1 substitution error: Invalid substitution: player_out 55 does not exist"
+    );
+}
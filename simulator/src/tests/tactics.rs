@@ -0,0 +1,81 @@
+use serde_json::from_str;
+use crate::models::game::game::Game;
+use crate::models::game::game_result::GameResult;
+use crate::tests::load_alpha_55;
+use crate::models::game::tactics::{ Mentality, Pressing, Tactics, Tempo, Width };
+
+/// Plays `games` matches where the first team uses `tactics`
+fn play_games(tactics: &Tactics, games: u64) -> Vec<GameResult> {
+    let mut teams = load_alpha_55();
+    teams[0].tactics = tactics.clone();
+
+    (0..games)
+        .map(|seed| {
+            Game::create_game(teams.clone(), Some(seed))
+                .expect("create_game failed")
+                .game_result.unwrap()
+        })
+        .collect()
+}
+
+fn first_team_total(results: &[GameResult], stat: fn(&GameResult) -> f32) -> f32 {
+    results.iter().map(stat).sum()
+}
+
+#[test]
+fn test_tactics_deserialize_with_defaults() {
+    let tactics: Tactics = from_str(r#"{ "mentality": "AllOutAttack", "pressing": "High" }"#).expect(
+        "Tactics deserialization failed"
+    );
+
+    assert_eq!(tactics.mentality, Mentality::AllOutAttack);
+    assert_eq!(tactics.pressing, Pressing::High);
+    assert_eq!(tactics.tempo, Tempo::Normal);
+    assert_eq!(load_alpha_55()[0].tactics, Tactics::default());
+}
+
+#[test]
+fn test_mentality_changes_shots() {
+    let attack = play_games(&Tactics { mentality: Mentality::AllOutAttack, ..Tactics::default() }, 40);
+    let defend = play_games(&Tactics { mentality: Mentality::UltraDefensive, ..Tactics::default() }, 40);
+
+    let shots = |r: &GameResult| r.teams_stats[0].shoots as f32;
+    let passes = |r: &GameResult| r.teams_stats[0].passes as f32;
+
+    assert!(first_team_total(&attack, shots) > first_team_total(&defend, shots));
+    assert!(first_team_total(&attack, passes) < first_team_total(&defend, passes));
+}
+
+#[test]
+fn test_width_changes_crosses() {
+    let wide = play_games(&Tactics { width: Width::Wide, ..Tactics::default() }, 40);
+    let narrow = play_games(&Tactics { width: Width::Narrow, ..Tactics::default() }, 40);
+
+    let crosses = |r: &GameResult| r.teams_stats[0].cross as f32;
+
+    assert!(first_team_total(&wide, crosses) > first_team_total(&narrow, crosses));
+}
+
+#[test]
+fn test_tempo_changes_actions() {
+    let fast = play_games(&Tactics { tempo: Tempo::Fast, ..Tactics::default() }, 20);
+    let slow = play_games(&Tactics { tempo: Tempo::Slow, ..Tactics::default() }, 20);
+
+    let actions = |r: &GameResult| (r.teams_stats[0].actions + r.teams_stats[1].actions) as f32;
+    let long_passes = |r: &GameResult| r.teams_stats[0].long_pass as f32;
+
+    assert!(first_team_total(&fast, actions) > first_team_total(&slow, actions));
+    assert!(first_team_total(&fast, long_passes) > first_team_total(&slow, long_passes));
+}
+
+#[test]
+fn test_pressing_costs_energy_and_fouls() {
+    let high = play_games(&Tactics { pressing: Pressing::High, ..Tactics::default() }, 40);
+    let low = play_games(&Tactics { pressing: Pressing::Low, ..Tactics::default() }, 40);
+
+    let energy = |r: &GameResult| r.energy[0].iter().map(|p| p.energy).sum::<f32>();
+    let fouls = |r: &GameResult| r.teams_stats[0].fouls as f32;
+
+    assert!(first_team_total(&high, energy) < first_team_total(&low, energy));
+    assert!(first_team_total(&high, fouls) > first_team_total(&low, fouls));
+}
@@ -0,0 +1,67 @@
+use crate::models::game::booking::BookingType;
+use crate::models::game::game::Game;
+use crate::tests::load_alpha_55;
+
+fn count_logs(logs: &[crate::models::game::log::Log], team_name: &str, description: &str) -> i32 {
+    logs.iter()
+        .filter(|l| l.team_name == team_name && l.description == description)
+        .count() as i32
+}
+
+#[test]
+fn test_team_stats_match_the_logs() {
+    let teams = load_alpha_55();
+
+    for seed in 0..50 {
+        let res = Game::create_game(teams.clone(), Some(seed)).expect("create_game failed");
+        let game_result = res.game_result.unwrap();
+
+        let possession: f32 = game_result.teams_stats.iter().map(|s| s.possession).sum();
+        assert!((possession - 100.0).abs() < 0.01, "Possession must add up to 100, got {}", possession);
+
+        for (i, stats) in game_result.teams_stats.iter().enumerate() {
+            let team_name = &teams[i].name;
+
+            assert_eq!(stats.scorers.len(), game_result.score[i] as usize);
+
+            assert!(stats.passes_suc <= stats.passes);
+            assert!(stats.shoots_suc <= stats.shoots);
+            assert!(stats.dribbles_suc <= stats.dribbles);
+            assert!(stats.long_pass_suc <= stats.long_pass);
+            assert!(stats.cross_suc <= stats.cross);
+            assert!(stats.controls_suc <= stats.controls);
+
+            assert_eq!(
+                stats.passes_suc,
+                count_logs(&res.logs, team_name, "success.pass") +
+                    count_logs(&res.logs, team_name, "success.long_pass")
+            );
+            assert_eq!(
+                stats.cross,
+                count_logs(&res.logs, team_name, "success.cross") +
+                    count_logs(&res.logs, team_name, "failed.cross")
+            );
+            assert_eq!(stats.penalties_goals, count_logs(&res.logs, team_name, "goal.penalty"));
+            assert_eq!(stats.corners_goals, count_logs(&res.logs, team_name, "goal.corner"));
+            assert_eq!(stats.free_kicks_goals, count_logs(&res.logs, team_name, "goal.free_kick"));
+            assert_eq!(stats.fouls, count_logs(&res.logs, team_name, "foul.dribble"));
+
+            let yellows = game_result.bookings
+                .iter()
+                .filter(|b| b.team_index as usize == i && b.card != BookingType::Red)
+                .count();
+            let reds = game_result.bookings
+                .iter()
+                .filter(|b| b.team_index as usize == i && b.card.is_sending_off())
+                .count();
+            assert_eq!(stats.yellow_cards.len(), yellows);
+            assert_eq!(stats.red_cards.len(), reds);
+
+            let injuries = game_result.injuries
+                .iter()
+                .filter(|inj| inj.team_index as usize == i)
+                .count();
+            assert_eq!(stats.injuries.len(), injuries);
+        }
+    }
+}
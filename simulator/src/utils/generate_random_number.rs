@@ -1,7 +1,27 @@
 use rand::Rng;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// Random number generator shared by every roll of a match
+pub type GameRng = StdRng;
 
 /// Generates a random number between a and b (inclusive)
 pub fn generate_number_by_range(a: u8, b: u8) -> u8 {
     let mut rng = rand::rng();
     rng.random_range(a..=b)
 }
+
+/// Generates a random number between a and b (inclusive) using the match rng
+pub fn generate_seeded_number_by_range(rng: &mut GameRng, a: u8, b: u8) -> u8 {
+    rng.random_range(a..=b)
+}
+
+/// Generates a fresh seed for matches that don't provide one
+pub fn generate_seed() -> u64 {
+    rand::rng().random()
+}
+
+/// Creates the match rng from a seed, the same seed always gives the same rolls
+pub fn create_game_rng(seed: u64) -> GameRng {
+    StdRng::seed_from_u64(seed)
+}
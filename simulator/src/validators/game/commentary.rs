@@ -0,0 +1,34 @@
+/// Languages are the name of their templates file in `src/data/commentary`,
+/// like `en` or `pt-br`
+pub fn validate_lang(lang: &str) -> Result<(), String> {
+    let valid =
+        (2..=5).contains(&lang.len()) &&
+        lang.chars().all(|c| c.is_ascii_lowercase() || c == '-') &&
+        !lang.starts_with('-');
+
+    if !valid {
+        return Err(format!("Invalid lang: {}", lang));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_langs() {
+        assert!(validate_lang("en").is_ok());
+        assert!(validate_lang("es").is_ok());
+        assert!(validate_lang("pt-br").is_ok());
+    }
+
+    #[test]
+    fn test_invalid_langs() {
+        assert!(validate_lang("").is_err());
+        assert!(validate_lang("EN").is_err());
+        assert!(validate_lang("../en").is_err());
+        assert!(validate_lang("english").is_err());
+    }
+}
@@ -0,0 +1,29 @@
+/// Fastest pace of a live match, the 90 minutes in about a second
+pub const MAX_LIVE_SCALE: u32 = 5400;
+
+/// The scale is how many match minutes are played every real minute, 1 is real time
+pub fn validate_scale(scale: u32) -> Result<(), String> {
+    if !(1..=MAX_LIVE_SCALE).contains(&scale) {
+        return Err(format!("Scale must be between 1 and {}, got {}", MAX_LIVE_SCALE, scale));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_scales() {
+        assert!(validate_scale(1).is_ok());
+        assert!(validate_scale(60).is_ok());
+        assert!(validate_scale(MAX_LIVE_SCALE).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_scales() {
+        assert!(validate_scale(0).is_err());
+        assert!(validate_scale(MAX_LIVE_SCALE + 1).is_err());
+    }
+}
@@ -0,0 +1,122 @@
+use crate::models::game::manager_rule::{ ManagerRule, RuleAction };
+use crate::models::player::position::Position;
+
+pub fn validate_manager_rules(
+    rules: &[ManagerRule],
+    players: usize,
+    bench_players: usize
+) -> Result<(), String> {
+    for (i, rule) in rules.iter().enumerate() {
+        if rule.then.is_empty() {
+            return Err(format!("Invalid manager rule {}: no actions", i));
+        }
+
+        match (rule.when.from_minute, rule.when.to_minute) {
+            (Some(from), Some(to)) if from > to => {
+                return Err(
+                    format!("Invalid manager rule {}: from_minute {} is after to_minute {}", i, from, to)
+                );
+            }
+            _ => {}
+        }
+
+        for action in &rule.then {
+            match action {
+                RuleAction::Substitution { player_out, player_in } => {
+                    if (*player_out as usize) >= players {
+                        return Err(
+                            format!("Invalid manager rule {}: player_out {} does not exist", i, player_out)
+                        );
+                    }
+                    if (*player_in as usize) >= bench_players {
+                        return Err(
+                            format!("Invalid manager rule {}: player_in {} does not exist", i, player_in)
+                        );
+                    }
+                }
+                RuleAction::Position { player, position } => {
+                    if (*player as usize) >= players {
+                        return Err(format!("Invalid manager rule {}: player {} does not exist", i, player));
+                    }
+                    if *position == Position::Goalkeeper {
+                        return Err(
+                            format!("Invalid manager rule {}: a player can't be moved to goalkeeper", i)
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::game::manager_rule::RuleCondition;
+    use crate::models::game::tactics::Mentality;
+
+    fn rule(when: RuleCondition, then: Vec<RuleAction>) -> ManagerRule {
+        ManagerRule { when, then, fired: false }
+    }
+
+    #[test]
+    fn test_valid_rules() {
+        let rules = vec![
+            rule(
+                RuleCondition { from_minute: Some(70), max_goal_difference: Some(-1), ..Default::default() },
+                vec![
+                    RuleAction::Mentality(Mentality::Attacking),
+                    RuleAction::Substitution { player_out: 10, player_in: 6 }
+                ]
+            ),
+            rule(
+                RuleCondition { min_red_cards: Some(1), ..Default::default() },
+                vec![RuleAction::Position { player: 9, position: Position::Defensive_Midfield }]
+            )
+        ];
+
+        assert!(validate_manager_rules(&rules, 11, 7).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let no_actions = vec![rule(RuleCondition::default(), vec![])];
+        assert_eq!(
+            validate_manager_rules(&no_actions, 11, 7).unwrap_err(),
+            "Invalid manager rule 0: no actions"
+        );
+
+        let minutes = vec![
+            rule(
+                RuleCondition { from_minute: Some(80), to_minute: Some(60), ..Default::default() },
+                vec![RuleAction::Mentality(Mentality::Defensive)]
+            )
+        ];
+        assert_eq!(
+            validate_manager_rules(&minutes, 11, 7).unwrap_err(),
+            "Invalid manager rule 0: from_minute 80 is after to_minute 60"
+        );
+
+        let bench = vec![
+            rule(RuleCondition::default(), vec![RuleAction::Substitution { player_out: 1, player_in: 7 }])
+        ];
+        assert_eq!(
+            validate_manager_rules(&bench, 11, 7).unwrap_err(),
+            "Invalid manager rule 0: player_in 7 does not exist"
+        );
+
+        let goalkeeper = vec![
+            rule(
+                RuleCondition::default(),
+                vec![RuleAction::Position { player: 3, position: Position::Goalkeeper }]
+            )
+        ];
+        assert_eq!(
+            validate_manager_rules(&goalkeeper, 11, 7).unwrap_err(),
+            "Invalid manager rule 0: a player can't be moved to goalkeeper"
+        );
+    }
+}
@@ -1 +1,6 @@
-pub mod lineup;
\ No newline at end of file
+pub mod lineup;
+pub mod squad;
+pub mod substitutions;
+pub mod manager_rules;
+pub mod commentary;
+pub mod live;
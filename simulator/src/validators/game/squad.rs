@@ -0,0 +1,16 @@
+use crate::models::game::team::Team;
+
+pub fn validate_squads(teams: &[Team; 2]) -> Result<(), String> {
+    // Validate starters and bench for both teams
+    for (i, team) in teams.iter().enumerate() {
+        if team.players.len() != 11 {
+            return Err(format!("Team {} must have exactly 11 players", i + 1));
+        }
+
+        if team.bench_players.len() < 5 {
+            return Err(format!("Team {} must have at least 5 players on the bench", i + 1));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use crate::models::game::substitution::{
+    Substitution,
+    HALF_TIME_MINUTE,
+    MAX_SUBSTITUTIONS,
+    MAX_SUBSTITUTION_WINDOWS,
+};
+
+pub fn validate_substitutions(
+    substitutions: &[Substitution],
+    players: usize,
+    bench_players: usize,
+    last_minute: u8
+) -> Result<(), String> {
+    if substitutions.len() > MAX_SUBSTITUTIONS {
+        return Err(
+            format!(
+                "Invalid substitutions: maximum {} substitutions, got {}",
+                MAX_SUBSTITUTIONS,
+                substitutions.len()
+            )
+        );
+    }
+
+    let mut windows: Vec<u8> = Vec::new();
+    let mut used_bench: Vec<u8> = Vec::new();
+
+    for sub in substitutions {
+        if !(1..=last_minute).contains(&sub.minute) {
+            return Err(
+                format!("Invalid substitution: minute {} out of range 1-{}", sub.minute, last_minute)
+            );
+        }
+
+        if (sub.player_out as usize) >= players {
+            return Err(format!("Invalid substitution: player_out {} does not exist", sub.player_out));
+        }
+
+        if (sub.player_in as usize) >= bench_players {
+            return Err(format!("Invalid substitution: player_in {} does not exist", sub.player_in));
+        }
+
+        if used_bench.contains(&sub.player_in) {
+            return Err(format!("Invalid substitution: player_in {} is used twice", sub.player_in));
+        }
+        used_bench.push(sub.player_in);
+
+        if sub.minute != HALF_TIME_MINUTE && !windows.contains(&sub.minute) {
+            windows.push(sub.minute);
+        }
+    }
+
+    if windows.len() > MAX_SUBSTITUTION_WINDOWS {
+        return Err(
+            format!(
+                "Invalid substitutions: maximum {} windows, got {}",
+                MAX_SUBSTITUTION_WINDOWS,
+                windows.len()
+            )
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sub(minute: u8, player_out: u8, player_in: u8) -> Substitution {
+        Substitution { minute, player_out, player_in }
+    }
+
+    #[test]
+    fn test_valid_substitutions() {
+        let subs = vec![sub(45, 1, 1), sub(45, 2, 2), sub(60, 9, 5), sub(75, 10, 6), sub(75, 3, 3)];
+
+        assert!(validate_substitutions(&subs, 11, 7, 90).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_player_out() {
+        let subs = vec![sub(45, 55, 1)];
+
+        let result = validate_substitutions(&subs, 11, 7, 90);
+        assert_eq!(result.unwrap_err(), "Invalid substitution: player_out 55 does not exist");
+    }
+
+    #[test]
+    fn test_invalid_player_in() {
+        let subs = vec![sub(45, 1, 7)];
+
+        let result = validate_substitutions(&subs, 11, 7, 90);
+        assert_eq!(result.unwrap_err(), "Invalid substitution: player_in 7 does not exist");
+    }
+
+    #[test]
+    fn test_bench_player_used_twice() {
+        let subs = vec![sub(45, 1, 2), sub(60, 3, 2)];
+
+        let result = validate_substitutions(&subs, 11, 7, 90);
+        assert_eq!(result.unwrap_err(), "Invalid substitution: player_in 2 is used twice");
+    }
+
+    #[test]
+    fn test_invalid_minute() {
+        let subs = vec![sub(95, 1, 1)];
+
+        let result = validate_substitutions(&subs, 11, 7, 90);
+        assert_eq!(result.unwrap_err(), "Invalid substitution: minute 95 out of range 1-90");
+    }
+
+    #[test]
+    fn test_extra_time_minute() {
+        let subs = vec![sub(95, 1, 1), sub(105, 2, 2)];
+        assert!(validate_substitutions(&subs, 11, 7, 120).is_ok());
+
+        let result = validate_substitutions(&[sub(121, 1, 1)], 11, 7, 120);
+        assert_eq!(result.unwrap_err(), "Invalid substitution: minute 121 out of range 1-120");
+    }
+
+    #[test]
+    fn test_too_many_substitutions() {
+        let subs = vec![
+            sub(45, 1, 1),
+            sub(45, 2, 2),
+            sub(45, 3, 3),
+            sub(45, 4, 4),
+            sub(45, 5, 5),
+            sub(45, 6, 6)
+        ];
+
+        let result = validate_substitutions(&subs, 11, 7, 90);
+        assert_eq!(result.unwrap_err(), "Invalid substitutions: maximum 5 substitutions, got 6");
+    }
+
+    #[test]
+    fn test_too_many_windows() {
+        let subs = vec![sub(50, 1, 1), sub(60, 2, 2), sub(70, 3, 3), sub(80, 4, 4)];
+
+        let result = validate_substitutions(&subs, 11, 7, 90);
+        assert_eq!(result.unwrap_err(), "Invalid substitutions: maximum 3 windows, got 4");
+    }
+}